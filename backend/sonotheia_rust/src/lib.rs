@@ -16,8 +16,14 @@ use pyo3::prelude::*;
 
 pub use sensors::{
     articulation::ArticulationSensor,
+    chroma::ChromaSensor,
+    coherence::CoherenceSensor,
+    periodicity::PeriodicitySensor,
     phase::PhaseSensor,
+    pitch::PitchSensor,
+    prosody::ProsodySensor,
     result::SensorResult,
+    tonality::TonalitySensor,
     vacuum::VacuumSensor,
 };
 pub use utils::errors::SensorError;
@@ -29,6 +35,12 @@ fn sonotheia_rust(_py: Python<'_>, m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<VacuumSensor>()?;
     m.add_class::<PhaseSensor>()?;
     m.add_class::<ArticulationSensor>()?;
+    m.add_class::<PitchSensor>()?;
+    m.add_class::<CoherenceSensor>()?;
+    m.add_class::<PeriodicitySensor>()?;
+    m.add_class::<ChromaSensor>()?;
+    m.add_class::<ProsodySensor>()?;
+    m.add_class::<TonalitySensor>()?;
     m.add_class::<SensorResult>()?;
 
     // Add version info
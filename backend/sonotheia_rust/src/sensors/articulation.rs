@@ -9,11 +9,14 @@ use pyo3::prelude::*;
 
 use crate::sensors::result::SensorResult;
 use crate::utils::audio::{
-    apply_hamming_window, calculate_rms, frame_audio, validate_audio_input, zero_crossing_rate,
+    apply_hamming_window, calculate_rms, downmix_interleaved, frame_audio, resample_to,
+    validate_audio_input, zero_crossing_rate, CANONICAL_SAMPLE_RATE,
 };
 use crate::utils::fft::{
-    compute_fft, frequency_bins, magnitude_spectrum, spectral_centroid, spectral_rolloff,
+    compute_fft, frequency_bins, magnitude_spectrum, spectral_centroid, spectral_flatness,
+    spectral_rolloff,
 };
+use crate::utils::lpc::{autocorrelation, formants_from_lpc, levinson_durbin, lpc_order};
 
 /// Default threshold for articulation sensor pass/fail decision
 const DEFAULT_THRESHOLD: f64 = 0.6;
@@ -24,6 +27,23 @@ const FRAME_SIZE: usize = 320;
 /// Hop size in samples (10ms at 16kHz)
 const HOP_SIZE: usize = 160;
 
+/// Number of lowest LPC resonances tracked per frame (F1-F3)
+const MAX_FORMANTS: usize = 3;
+
+/// Minimum fraction of frames needing a stable LPC solve before the
+/// formant-trajectory metric is trusted over the centroid-based fallback
+const MIN_FORMANT_COVERAGE: f64 = 0.5;
+
+/// Standard deviation (Hz) of the frame-to-frame F1/F2 movement below
+/// which a formant track is "too uniform" -- implausibly static, as if
+/// frozen between phonemes
+const FORMANT_DELTA_TOO_STATIC: f64 = 20.0;
+
+/// Standard deviation (Hz) of the frame-to-frame F1/F2 movement above
+/// which a formant track is "too erratic" -- stair-stepped or jumping
+/// rather than gliding
+const FORMANT_DELTA_TOO_ERRATIC: f64 = 600.0;
+
 /// Articulation Sensor for speech pattern analysis
 ///
 /// This sensor analyzes articulation patterns in speech:
@@ -74,32 +94,81 @@ impl ArticulationSensor {
     /// Analyze audio data for articulation patterns
     ///
     /// # Arguments
-    /// * `audio` - Audio samples as numpy array (f64)
+    /// * `audio` - Audio samples as numpy array (f64), interleaved across
+    ///   channels when `channels` > 1
     /// * `sample_rate` - Sample rate in Hz
+    /// * `channels` - Number of interleaved channels in `audio`, default 1
+    ///   (mono); multichannel input is averaged down to mono before analysis
     ///
     /// # Returns
     /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (audio, sample_rate, channels=None))]
     pub fn analyze(
         &self,
         audio: PyReadonlyArray1<'_, f64>,
         sample_rate: u32,
+        channels: Option<u32>,
     ) -> PyResult<SensorResult> {
         let audio_slice = audio.as_slice()?;
+        let mono = downmix_interleaved(audio_slice, channels);
+        Ok(self.analyze_samples(&mono, sample_rate))
+    }
+
+    /// Load, resample, and analyze an audio file in one call
+    ///
+    /// # Arguments
+    /// * `path` - Path to an audio file decodable by `utils::io`
+    /// * `target_rate` - Sample rate to resample to before analysis, default 16000
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (path, target_rate=None))]
+    pub fn analyze_file(&self, path: String, target_rate: Option<u32>) -> PyResult<SensorResult> {
+        let rate = target_rate.unwrap_or(crate::utils::io::DEFAULT_TARGET_RATE);
+        match crate::utils::io::load_and_prepare(&path, rate) {
+            Ok(audio) => Ok(self.analyze_samples(&audio, rate)),
+            Err(e) => Ok(SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("io_error".to_string()),
+                Some(format!("Failed to load audio file: {}", e)),
+            )),
+        }
+    }
 
+    /// String representation for Python
+    fn __repr__(&self) -> String {
+        format!(
+            "ArticulationSensor(name='{}', threshold={})",
+            self.name, self.threshold
+        )
+    }
+}
+
+impl ArticulationSensor {
+    /// Analyze a raw sample slice, shared by `analyze` and `analyze_file`
+    fn analyze_samples(&self, audio_slice: &[f64], sample_rate: u32) -> SensorResult {
         // Validate input
         if let Err(e) = validate_audio_input(audio_slice, sample_rate) {
-            return Ok(SensorResult::new(
+            return SensorResult::new(
                 self.name.clone(),
                 Some(false),
                 0.0,
                 self.threshold,
                 Some("validation_error".to_string()),
                 Some(format!("Input validation failed: {}", e)),
-            ));
+            );
         }
 
         // Perform analysis
-        let authenticity_score = self.compute_articulation_score(audio_slice, sample_rate);
+        // Resample to the canonical rate so the sensor's frame/hop
+        // constants apply exactly regardless of the input sample rate
+        let resampled = resample_to(audio_slice, sample_rate, CANONICAL_SAMPLE_RATE);
+
+        let authenticity_score =
+            self.compute_articulation_score(&resampled, CANONICAL_SAMPLE_RATE);
 
         // Determine pass/fail
         let passed = authenticity_score >= self.threshold;
@@ -116,7 +185,7 @@ impl ArticulationSensor {
             )
         };
 
-        Ok(SensorResult::new(
+        SensorResult::new(
             self.name.clone(),
             Some(passed),
             authenticity_score,
@@ -127,24 +196,14 @@ impl ArticulationSensor {
                 None
             },
             Some(detail),
-        ))
-    }
-
-    /// String representation for Python
-    fn __repr__(&self) -> String {
-        format!(
-            "ArticulationSensor(name='{}', threshold={})",
-            self.name, self.threshold
         )
     }
-}
-
-impl ArticulationSensor {
     /// Compute articulation authenticity score
     fn compute_articulation_score(&self, audio: &[f64], sample_rate: u32) -> f64 {
-        // Adjust frame parameters based on sample rate
-        let frame_size = (sample_rate as usize * FRAME_SIZE) / 16000;
-        let hop_size = (sample_rate as usize * HOP_SIZE) / 16000;
+        // Audio has already been resampled to `CANONICAL_SAMPLE_RATE`, so
+        // the frame/hop constants apply exactly
+        let frame_size = FRAME_SIZE;
+        let hop_size = HOP_SIZE;
 
         // Frame the audio
         let frames = frame_audio(audio, frame_size, hop_size);
@@ -183,6 +242,8 @@ impl ArticulationSensor {
 
                 // Spectral flux (change from previous frame)
                 let spectral_flux = self.compute_spectral_flux(&magnitudes);
+                let flatness = spectral_flatness(&magnitudes);
+                let formants = Self::extract_formants(&windowed, sample_rate);
 
                 frame_features.push(FrameFeatures {
                     rms,
@@ -190,7 +251,9 @@ impl ArticulationSensor {
                     centroid,
                     rolloff_freq,
                     spectral_flux,
+                    flatness,
                     magnitudes: magnitudes.clone(),
+                    formants,
                 });
             }
         }
@@ -203,16 +266,18 @@ impl ArticulationSensor {
         self.update_spectral_flux(&mut frame_features);
 
         // Compute articulation metrics
-        let transition_score = self.compute_transition_score(&frame_features);
+        let transition_score = self.compute_formant_transition_score(&frame_features);
         let dynamics_score = self.compute_dynamics_score(&frame_features);
         let zcr_pattern_score = self.compute_zcr_pattern_score(&frame_features);
         let spectral_flux_score = self.compute_spectral_flux_pattern_score(&frame_features);
+        let flatness_score = self.compute_flatness_pattern_score(&frame_features);
 
         // Combined score
-        let combined = 0.3 * transition_score
-            + 0.25 * dynamics_score
-            + 0.2 * zcr_pattern_score
-            + 0.25 * spectral_flux_score;
+        let combined = 0.25 * transition_score
+            + 0.2 * dynamics_score
+            + 0.15 * zcr_pattern_score
+            + 0.2 * spectral_flux_score
+            + 0.2 * flatness_score;
 
         combined.clamp(0.0, 1.0)
     }
@@ -240,9 +305,77 @@ impl ArticulationSensor {
         }
     }
 
-    /// Compute transition smoothness score
+    /// Solve for the lowest `MAX_FORMANTS` LPC resonance frequencies of a frame
+    ///
+    /// Returns an empty vector if the frame's autocorrelation is too
+    /// ill-conditioned (near-silent or otherwise degenerate) for a stable
+    /// Levinson-Durbin solve -- callers fall back to the centroid-based
+    /// transition estimate when too few frames yield formants.
+    fn extract_formants(windowed: &[f64], sample_rate: u32) -> Vec<f64> {
+        let order = lpc_order(sample_rate);
+        if windowed.len() <= order {
+            return Vec::new();
+        }
+
+        let r = autocorrelation(windowed, order);
+        match levinson_durbin(&r, order) {
+            Some(result) => formants_from_lpc(&result.coefficients, sample_rate, MAX_FORMANTS)
+                .into_iter()
+                .map(|f| f.frequency)
+                .collect(),
+            None => Vec::new(),
+        }
+    }
+
+    /// Compute the transition-smoothness score, preferring formant glides
+    ///
+    /// Natural coarticulation yields continuous, moderately-paced formant
+    /// glides between phonemes, while synthetic speech often shows
+    /// stair-stepped or implausibly static formant tracks. Falls back to
+    /// the coarser centroid-based estimate when too few frames produced a
+    /// stable LPC solve to trust a formant trajectory.
+    fn compute_formant_transition_score(&self, features: &[FrameFeatures]) -> f64 {
+        let tracked: Vec<&FrameFeatures> = features
+            .iter()
+            .filter(|f| f.formants.len() >= 2)
+            .collect();
+
+        if (tracked.len() as f64) < features.len() as f64 * MIN_FORMANT_COVERAGE {
+            return self.compute_transition_score(features);
+        }
+
+        let mut deltas: Vec<f64> = Vec::with_capacity(tracked.len().saturating_sub(1));
+        for pair in tracked.windows(2) {
+            let d_f1 = pair[1].formants[0] - pair[0].formants[0];
+            let d_f2 = pair[1].formants[1] - pair[0].formants[1];
+            deltas.push((d_f1 * d_f1 + d_f2 * d_f2).sqrt());
+        }
+
+        if deltas.is_empty() {
+            return self.compute_transition_score(features);
+        }
+
+        let mean_delta = deltas.iter().sum::<f64>() / deltas.len() as f64;
+        let variance =
+            deltas.iter().map(|&d| (d - mean_delta).powi(2)).sum::<f64>() / deltas.len() as f64;
+        let std_delta = variance.sqrt();
+
+        // Natural coarticulation glides at a moderate, variable pace;
+        // frozen or stair-stepped tracks cluster near-zero drift, while
+        // jumpy/unstable tracking swings wildly between frames
+        if std_delta < FORMANT_DELTA_TOO_STATIC {
+            (std_delta / FORMANT_DELTA_TOO_STATIC).min(1.0) * 0.6
+        } else if std_delta > FORMANT_DELTA_TOO_ERRATIC {
+            (FORMANT_DELTA_TOO_ERRATIC / std_delta).min(1.0) * 0.7
+        } else {
+            1.0
+        }
+    }
+
+    /// Compute transition smoothness score from spectral centroid deltas
     ///
-    /// Natural speech has smooth formant transitions during coarticulation
+    /// Fallback used when too few frames yield a stable formant solve;
+    /// natural speech has smooth spectral transitions during coarticulation
     fn compute_transition_score(&self, features: &[FrameFeatures]) -> f64 {
         if features.len() < 2 {
             return 0.5;
@@ -378,6 +511,37 @@ impl ArticulationSensor {
             1.0
         }
     }
+
+    /// Compute spectral flatness pattern score
+    ///
+    /// Flatness captures each frame's tonal-vs-noise balance (Wiener
+    /// entropy). Natural speech alternates between voiced, low-flatness
+    /// frames and fricative/unvoiced, high-flatness frames, so flatness
+    /// should vary across the utterance; synthetic speech -- especially
+    /// vocoder/GAN output -- tends toward a near-constant flatness.
+    fn compute_flatness_pattern_score(&self, features: &[FrameFeatures]) -> f64 {
+        if features.is_empty() {
+            return 0.5;
+        }
+
+        let flatness_values: Vec<f64> = features.iter().map(|f| f.flatness).collect();
+
+        let mean_flatness = flatness_values.iter().sum::<f64>() / flatness_values.len() as f64;
+        let variance = flatness_values
+            .iter()
+            .map(|&f| (f - mean_flatness).powi(2))
+            .sum::<f64>()
+            / flatness_values.len() as f64;
+        let std_flatness = variance.sqrt();
+
+        // Natural speech has varying flatness (voiced vs fricative frames)
+        if std_flatness < 0.03 {
+            // Too uniform - potentially synthetic
+            (std_flatness / 0.03).min(1.0) * 0.6
+        } else {
+            1.0
+        }
+    }
 }
 
 /// Internal structure for frame features
@@ -388,7 +552,11 @@ struct FrameFeatures {
     #[allow(dead_code)] // Reserved for future formant analysis
     rolloff_freq: f64,
     spectral_flux: f64,
+    flatness: f64,
     magnitudes: Vec<f64>,
+    /// Lowest `MAX_FORMANTS` LPC resonance frequencies (F1, F2, F3, ...),
+    /// empty if the frame's autocorrelation was too ill-conditioned to solve
+    formants: Vec<f64>,
 }
 
 #[cfg(test)]
@@ -420,7 +588,9 @@ mod tests {
                 centroid: 1000.0,
                 rolloff_freq: 3000.0,
                 spectral_flux: 0.0,
+                flatness: 0.4,
                 magnitudes: vec![],
+                formants: vec![],
             },
             FrameFeatures {
                 rms: 0.12,
@@ -428,7 +598,9 @@ mod tests {
                 centroid: 1100.0,
                 rolloff_freq: 3100.0,
                 spectral_flux: 0.1,
+                flatness: 0.45,
                 magnitudes: vec![],
+                formants: vec![],
             },
             FrameFeatures {
                 rms: 0.11,
@@ -436,7 +608,9 @@ mod tests {
                 centroid: 1050.0,
                 rolloff_freq: 2900.0,
                 spectral_flux: 0.08,
+                flatness: 0.42,
                 magnitudes: vec![],
+                formants: vec![],
             },
         ];
 
@@ -458,7 +632,9 @@ mod tests {
                 centroid: 1000.0,
                 rolloff_freq: 3000.0,
                 spectral_flux: 0.0,
+                flatness: 0.4,
                 magnitudes: vec![],
+                formants: vec![],
             },
             FrameFeatures {
                 rms: 0.3,
@@ -466,7 +642,9 @@ mod tests {
                 centroid: 1100.0,
                 rolloff_freq: 3100.0,
                 spectral_flux: 0.1,
+                flatness: 0.45,
                 magnitudes: vec![],
+                formants: vec![],
             },
             FrameFeatures {
                 rms: 0.05,
@@ -474,7 +652,9 @@ mod tests {
                 centroid: 1050.0,
                 rolloff_freq: 2900.0,
                 spectral_flux: 0.08,
+                flatness: 0.42,
                 magnitudes: vec![],
+                formants: vec![],
             },
         ];
 
@@ -482,4 +662,134 @@ mod tests {
         // Good dynamics should score well
         assert!(score > 0.5);
     }
+
+    #[test]
+    fn test_flatness_pattern_score_flags_constant_flatness() {
+        let sensor = ArticulationSensor::new(None);
+
+        let features: Vec<FrameFeatures> = (0..5)
+            .map(|_| FrameFeatures {
+                rms: 0.1,
+                zcr: 0.15,
+                centroid: 1000.0,
+                rolloff_freq: 3000.0,
+                spectral_flux: 0.05,
+                flatness: 0.4,
+                magnitudes: vec![],
+                formants: vec![],
+            })
+            .collect();
+
+        let score = sensor.compute_flatness_pattern_score(&features);
+        assert!(score < 0.6);
+    }
+
+    #[test]
+    fn test_flatness_pattern_score_rewards_voiced_fricative_alternation() {
+        let sensor = ArticulationSensor::new(None);
+
+        let features: Vec<FrameFeatures> = [0.2, 0.8, 0.15, 0.75, 0.25]
+            .iter()
+            .map(|&flatness| FrameFeatures {
+                rms: 0.1,
+                zcr: 0.15,
+                centroid: 1000.0,
+                rolloff_freq: 3000.0,
+                spectral_flux: 0.05,
+                flatness,
+                magnitudes: vec![],
+                formants: vec![],
+            })
+            .collect();
+
+        let score = sensor.compute_flatness_pattern_score(&features);
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+
+    fn formant_frame(f1: f64, f2: f64) -> FrameFeatures {
+        FrameFeatures {
+            rms: 0.1,
+            zcr: 0.15,
+            centroid: 1000.0,
+            rolloff_freq: 3000.0,
+            spectral_flux: 0.05,
+            flatness: 0.4,
+            magnitudes: vec![],
+            formants: vec![f1, f2],
+        }
+    }
+
+    #[test]
+    fn test_extract_formants_on_resonant_tone() {
+        let n = 320;
+        let sample_rate = 16000u32;
+        let freq = 700.0;
+        let windowed: Vec<f64> = (0..n)
+            .map(|i| (2.0 * std::f64::consts::PI * freq * i as f64 / sample_rate as f64).sin())
+            .collect();
+
+        let formants = ArticulationSensor::extract_formants(&windowed, sample_rate);
+        assert!(!formants.is_empty());
+    }
+
+    #[test]
+    fn test_extract_formants_empty_for_silent_frame() {
+        let silent = vec![0.0; 320];
+        let formants = ArticulationSensor::extract_formants(&silent, 16000);
+        assert!(formants.is_empty());
+    }
+
+    #[test]
+    fn test_formant_transition_score_flags_static_track() {
+        let sensor = ArticulationSensor::new(None);
+        let features = vec![formant_frame(500.0, 1500.0); 5];
+        let score = sensor.compute_formant_transition_score(&features);
+        assert!(score < 0.6);
+    }
+
+    #[test]
+    fn test_formant_transition_score_rewards_gliding_track() {
+        let sensor = ArticulationSensor::new(None);
+        let features = vec![
+            formant_frame(500.0, 1500.0),
+            formant_frame(560.0, 1580.0),
+            formant_frame(480.0, 1460.0),
+            formant_frame(540.0, 1620.0),
+            formant_frame(510.0, 1520.0),
+        ];
+        let score = sensor.compute_formant_transition_score(&features);
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_formant_transition_score_falls_back_without_coverage() {
+        let sensor = ArticulationSensor::new(None);
+        // No frame has a resolved formant track, so this must fall back to
+        // the centroid-based estimate rather than dividing by zero frames
+        let features = vec![
+            FrameFeatures {
+                rms: 0.1,
+                zcr: 0.15,
+                centroid: 1000.0,
+                rolloff_freq: 3000.0,
+                spectral_flux: 0.0,
+                flatness: 0.4,
+                magnitudes: vec![],
+                formants: vec![],
+            },
+            FrameFeatures {
+                rms: 0.12,
+                zcr: 0.18,
+                centroid: 1100.0,
+                rolloff_freq: 3100.0,
+                spectral_flux: 0.1,
+                flatness: 0.45,
+                magnitudes: vec![],
+                formants: vec![],
+            },
+        ];
+        let score = sensor.compute_formant_transition_score(&features);
+        let fallback = sensor.compute_transition_score(&features);
+        assert!((score - fallback).abs() < f64::EPSILON);
+    }
 }
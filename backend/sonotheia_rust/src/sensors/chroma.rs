@@ -0,0 +1,385 @@
+//! Chroma Sensor - Tonal Stationarity Detection
+//!
+//! Maps STFT magnitude energy into 12 pitch-class (chroma) bins and flags
+//! the unnaturally static harmonic structure typical of synthesized or
+//! looped content. Real recordings show continuous tonal drift and
+//! noise-driven spread across chroma bins, while synthetic/TTS audio
+//! produces chroma vectors that are either abnormally constant over time
+//! or collapse onto too few pitch classes.
+
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+
+use crate::sensors::result::SensorResult;
+use crate::utils::audio::{
+    apply_hamming_window, calculate_rms, downmix_interleaved, frame_audio, resample_to,
+    validate_audio_input, CANONICAL_SAMPLE_RATE,
+};
+use crate::utils::fft::{compute_fft, frequency_bins, magnitude_spectrum};
+
+/// Default threshold for chroma sensor pass/fail decision
+const DEFAULT_THRESHOLD: f64 = 0.6;
+
+/// STFT window size in samples (512ms at 16kHz)
+const FRAME_SIZE: usize = 8192;
+
+/// STFT hop size in samples (256ms at 16kHz)
+const HOP_SIZE: usize = 4096;
+
+/// Number of chroma (pitch-class) bins per octave
+const N_CHROMA_BINS: usize = 12;
+
+/// Reference frequency for octave/pitch-class mapping (C0, Hz)
+const F_REF: f64 = 16.35;
+
+/// Lowest analysis frequency -- below this, bins are too coarse to map reliably (Hz)
+const MIN_ANALYSIS_HZ: f64 = 40.0;
+
+/// Frame-to-frame cosine distance variance below which chroma is "too static"
+const STATIC_VARIANCE_THRESHOLD: f64 = 0.0005;
+
+/// Fraction of chroma energy concentrated in the single dominant bin above
+/// which chroma has "collapsed" onto too few pitch classes
+const COLLAPSE_THRESHOLD: f64 = 0.7;
+
+/// Chroma Sensor for tonal-stationarity detection
+///
+/// # Example
+/// ```python
+/// from sonotheia_rust import ChromaSensor
+///
+/// sensor = ChromaSensor()
+/// result = sensor.analyze(audio_data, 16000)
+/// print(f"Passed: {result.passed}, Score: {result.value}")
+/// ```
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ChromaSensor {
+    /// Detection threshold (0.0-1.0)
+    #[pyo3(get, set)]
+    pub threshold: f64,
+
+    /// Sensor name identifier
+    #[pyo3(get)]
+    pub name: String,
+}
+
+#[pymethods]
+impl ChromaSensor {
+    /// Create a new ChromaSensor with optional threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Detection threshold (0.0-1.0), default 0.6
+    #[new]
+    #[pyo3(signature = (threshold=None))]
+    pub fn new(threshold: Option<f64>) -> Self {
+        let threshold_value = threshold.unwrap_or(DEFAULT_THRESHOLD);
+        let clamped_threshold = threshold_value.clamp(0.0, 1.0);
+
+        Self {
+            threshold: clamped_threshold,
+            name: "chroma_sensor".to_string(),
+        }
+    }
+
+    /// Analyze audio data for tonal-stationarity anomalies
+    ///
+    /// # Arguments
+    /// * `audio` - Audio samples as numpy array (f64), interleaved across
+    ///   channels when `channels` > 1
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `channels` - Number of interleaved channels in `audio`, default 1
+    ///   (mono); multichannel input is averaged down to mono before analysis
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (audio, sample_rate, channels=None))]
+    pub fn analyze(
+        &self,
+        audio: PyReadonlyArray1<'_, f64>,
+        sample_rate: u32,
+        channels: Option<u32>,
+    ) -> PyResult<SensorResult> {
+        let audio_slice = audio.as_slice()?;
+        let mono = downmix_interleaved(audio_slice, channels);
+        Ok(self.analyze_samples(&mono, sample_rate))
+    }
+
+    /// Load, resample, and analyze an audio file in one call
+    ///
+    /// # Arguments
+    /// * `path` - Path to an audio file decodable by `utils::io`
+    /// * `target_rate` - Sample rate to resample to before analysis, default 16000
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (path, target_rate=None))]
+    pub fn analyze_file(&self, path: String, target_rate: Option<u32>) -> PyResult<SensorResult> {
+        let rate = target_rate.unwrap_or(crate::utils::io::DEFAULT_TARGET_RATE);
+        match crate::utils::io::load_and_prepare(&path, rate) {
+            Ok(audio) => Ok(self.analyze_samples(&audio, rate)),
+            Err(e) => Ok(SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("io_error".to_string()),
+                Some(format!("Failed to load audio file: {}", e)),
+            )),
+        }
+    }
+
+    /// String representation for Python
+    fn __repr__(&self) -> String {
+        format!(
+            "ChromaSensor(name='{}', threshold={})",
+            self.name, self.threshold
+        )
+    }
+}
+
+impl ChromaSensor {
+    /// Analyze a raw sample slice, shared by `analyze` and `analyze_file`
+    fn analyze_samples(&self, audio_slice: &[f64], sample_rate: u32) -> SensorResult {
+        // Validate input
+        if let Err(e) = validate_audio_input(audio_slice, sample_rate) {
+            return SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("validation_error".to_string()),
+                Some(format!("Input validation failed: {}", e)),
+            );
+        }
+
+        // Perform analysis
+        // Resample to the canonical rate so the sensor's frame/hop
+        // constants apply exactly regardless of the input sample rate
+        let resampled = resample_to(audio_slice, sample_rate, CANONICAL_SAMPLE_RATE);
+
+        let authenticity_score =
+            self.compute_tonal_stationarity_score(&resampled, CANONICAL_SAMPLE_RATE);
+
+        // Determine pass/fail
+        let passed = authenticity_score >= self.threshold;
+
+        let detail = if passed {
+            format!(
+                "Chroma tonal-stationarity analysis passed (score: {:.2})",
+                authenticity_score
+            )
+        } else {
+            format!(
+                "Abnormally static or collapsed chroma detected (score: {:.2})",
+                authenticity_score
+            )
+        };
+
+        SensorResult::new(
+            self.name.clone(),
+            Some(passed),
+            authenticity_score,
+            self.threshold,
+            if !passed {
+                Some("chroma_anomaly".to_string())
+            } else {
+                None
+            },
+            Some(detail),
+        )
+    }
+
+    /// Compute tonal-stationarity authenticity score
+    fn compute_tonal_stationarity_score(&self, audio: &[f64], sample_rate: u32) -> f64 {
+        // Audio has already been resampled to `CANONICAL_SAMPLE_RATE`, so
+        // the frame/hop constants apply exactly
+        let frame_size = FRAME_SIZE;
+        let hop_size = HOP_SIZE;
+
+        let frames = frame_audio(audio, frame_size, hop_size);
+
+        if frames.len() < 3 {
+            return 0.5; // Neutral score for insufficient data
+        }
+
+        let frequencies = frequency_bins(frame_size, sample_rate);
+
+        let mut chroma_vectors: Vec<[f64; N_CHROMA_BINS]> = Vec::with_capacity(frames.len());
+
+        for frame in &frames {
+            let windowed = apply_hamming_window(frame);
+
+            // Skip silent frames
+            if calculate_rms(&windowed) < 1e-6 {
+                continue;
+            }
+
+            if let Ok(fft_result) = compute_fft(&windowed) {
+                let magnitudes = magnitude_spectrum(&fft_result);
+                chroma_vectors.push(self.chroma_vector(&magnitudes, &frequencies));
+            }
+        }
+
+        if chroma_vectors.len() < 3 {
+            return 0.5; // Not enough frames to judge temporal variability
+        }
+
+        let variability_score = self.compute_variability_score(&chroma_vectors);
+        let collapse_score = self.compute_collapse_score(&chroma_vectors);
+
+        (0.5 * variability_score + 0.5 * collapse_score).clamp(0.0, 1.0)
+    }
+
+    /// Build an L1-normalized 12-bin chroma vector from a magnitude spectrum
+    ///
+    /// Each bin's center frequency is mapped to a pitch class via
+    /// `12 * log2(f / f_ref) mod 12`, and its magnitude is accumulated into
+    /// the nearest chroma bin.
+    fn chroma_vector(&self, magnitudes: &[f64], frequencies: &[f64]) -> [f64; N_CHROMA_BINS] {
+        let mut chroma = [0.0; N_CHROMA_BINS];
+
+        for (&magnitude, &freq) in magnitudes.iter().zip(frequencies.iter()) {
+            if freq < MIN_ANALYSIS_HZ {
+                continue;
+            }
+
+            let pitch_class = 12.0 * (freq / F_REF).log2();
+            let bin = pitch_class.round().rem_euclid(N_CHROMA_BINS as f64) as usize;
+            chroma[bin] += magnitude;
+        }
+
+        let total: f64 = chroma.iter().sum();
+        if total > 1e-12 {
+            for value in chroma.iter_mut() {
+                *value /= total;
+            }
+        }
+
+        chroma
+    }
+
+    /// Cosine distance between two chroma vectors
+    fn cosine_distance(a: &[f64; N_CHROMA_BINS], b: &[f64; N_CHROMA_BINS]) -> f64 {
+        let dot: f64 = a.iter().zip(b.iter()).map(|(&x, &y)| x * y).sum();
+        let norm_a = a.iter().map(|&x| x * x).sum::<f64>().sqrt();
+        let norm_b = b.iter().map(|&x| x * x).sum::<f64>().sqrt();
+
+        if norm_a < 1e-12 || norm_b < 1e-12 {
+            return 0.0;
+        }
+
+        (1.0 - (dot / (norm_a * norm_b))).clamp(0.0, 2.0)
+    }
+
+    /// Score the variance of frame-to-frame chroma cosine distance
+    ///
+    /// Real recordings show continuous tonal drift and noise-driven spread
+    /// across chroma bins; synthetic/TTS audio produces a chroma vector
+    /// that is abnormally constant over time (near-zero variance).
+    fn compute_variability_score(&self, chroma_vectors: &[[f64; N_CHROMA_BINS]]) -> f64 {
+        let distances: Vec<f64> = chroma_vectors
+            .windows(2)
+            .map(|pair| Self::cosine_distance(&pair[0], &pair[1]))
+            .collect();
+
+        let mean = distances.iter().sum::<f64>() / distances.len() as f64;
+        let variance = distances.iter().map(|&d| (d - mean).powi(2)).sum::<f64>()
+            / distances.len() as f64;
+
+        if variance < STATIC_VARIANCE_THRESHOLD {
+            (variance / STATIC_VARIANCE_THRESHOLD).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Score how much chroma energy collapses onto a single pitch class
+    ///
+    /// Averages the per-frame dominant-bin energy fraction; synthetic
+    /// content often concentrates almost all energy in too few pitch
+    /// classes rather than spreading it naturally across the octave.
+    fn compute_collapse_score(&self, chroma_vectors: &[[f64; N_CHROMA_BINS]]) -> f64 {
+        let dominant_fractions: Vec<f64> = chroma_vectors
+            .iter()
+            .map(|chroma| chroma.iter().cloned().fold(0.0_f64, f64::max))
+            .collect();
+
+        let mean_dominant =
+            dominant_fractions.iter().sum::<f64>() / dominant_fractions.len() as f64;
+
+        if mean_dominant > COLLAPSE_THRESHOLD {
+            ((1.0 - mean_dominant) / (1.0 - COLLAPSE_THRESHOLD)).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_chroma_sensor_creation() {
+        let sensor = ChromaSensor::new(None);
+        assert_eq!(sensor.name, "chroma_sensor");
+        assert!((sensor.threshold - DEFAULT_THRESHOLD).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_chroma_sensor_custom_threshold() {
+        let sensor = ChromaSensor::new(Some(0.8));
+        assert!((sensor.threshold - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_chroma_vector_is_normalized() {
+        let sensor = ChromaSensor::new(None);
+        let frequencies = vec![100.0, 200.0, 440.0, 880.0];
+        let magnitudes = vec![1.0, 2.0, 3.0, 4.0];
+        let chroma = sensor.chroma_vector(&magnitudes, &frequencies);
+        let total: f64 = chroma.iter().sum();
+        assert!((total - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_chroma_vector_same_pitch_class_accumulates() {
+        let sensor = ChromaSensor::new(None);
+        // 220Hz and 880Hz (two octaves up) map to the same pitch class
+        let frequencies = vec![220.0, 880.0];
+        let magnitudes = vec![1.0, 1.0];
+        let chroma = sensor.chroma_vector(&magnitudes, &frequencies);
+        let nonzero_bins = chroma.iter().filter(|&&c| c > 1e-9).count();
+        assert_eq!(nonzero_bins, 1);
+    }
+
+    #[test]
+    fn test_variability_score_flags_static_chroma() {
+        let sensor = ChromaSensor::new(None);
+        let mut chroma = [0.0; N_CHROMA_BINS];
+        chroma[0] = 1.0;
+        let vectors = vec![chroma; 10];
+        let score = sensor.compute_variability_score(&vectors);
+        assert!(score < 0.5);
+    }
+
+    #[test]
+    fn test_collapse_score_flags_single_bin_dominance() {
+        let sensor = ChromaSensor::new(None);
+        let mut chroma = [0.01; N_CHROMA_BINS];
+        chroma[0] = 1.0 - 0.01 * (N_CHROMA_BINS as f64 - 1.0);
+        let vectors = vec![chroma; 5];
+        let score = sensor.compute_collapse_score(&vectors);
+        assert!(score < 0.5);
+    }
+
+    #[test]
+    fn test_collapse_score_rewards_spread_energy() {
+        let sensor = ChromaSensor::new(None);
+        let chroma = [1.0 / N_CHROMA_BINS as f64; N_CHROMA_BINS];
+        let vectors = vec![chroma; 5];
+        let score = sensor.compute_collapse_score(&vectors);
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+}
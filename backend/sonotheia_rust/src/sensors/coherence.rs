@@ -0,0 +1,269 @@
+//! Coherence Sensor - Cross-Channel Magnitude-Squared Coherence
+//!
+//! Detects synthesis artifacts by comparing two input channels (a stereo
+//! pair, or an original vs. a suspect rendering) via the magnitude-squared
+//! coherence, estimated with Welch averaging. Genuine acoustic captures
+//! show coherence that decays with frequency and dips between formants,
+//! while fully synthetic or upsampled content is often near-perfectly
+//! coherent across the whole band or collapses abruptly at a synthesis
+//! cutoff.
+
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+
+use crate::sensors::result::SensorResult;
+use crate::utils::audio::validate_audio_input;
+use crate::utils::fft::{frequency_bins, magnitude_squared_coherence, welch_spectra};
+
+/// Default threshold for coherence sensor pass/fail decision
+const DEFAULT_THRESHOLD: f64 = 0.6;
+
+/// Welch segment size in samples (64ms at 16kHz)
+const SEGMENT_SIZE: usize = 1024;
+
+/// Welch hop size in samples (50% overlap)
+const HOP_SIZE: usize = SEGMENT_SIZE / 2;
+
+/// Coherence value above which a bin is considered "plateaued"
+const PLATEAU_THRESHOLD: f64 = 0.97;
+
+/// Coherence Sensor for cross-channel magnitude-squared coherence analysis
+///
+/// # Example
+/// ```python
+/// from sonotheia_rust import CoherenceSensor
+///
+/// sensor = CoherenceSensor()
+/// result = sensor.analyze(channel_a, channel_b, 16000)
+/// print(f"Passed: {result.passed}, Score: {result.value}")
+/// ```
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct CoherenceSensor {
+    /// Detection threshold (0.0-1.0)
+    #[pyo3(get, set)]
+    pub threshold: f64,
+
+    /// Sensor name identifier
+    #[pyo3(get)]
+    pub name: String,
+}
+
+#[pymethods]
+impl CoherenceSensor {
+    /// Create a new CoherenceSensor with optional threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Detection threshold (0.0-1.0), default 0.6
+    #[new]
+    #[pyo3(signature = (threshold=None))]
+    pub fn new(threshold: Option<f64>) -> Self {
+        let threshold_value = threshold.unwrap_or(DEFAULT_THRESHOLD);
+        let clamped_threshold = threshold_value.clamp(0.0, 1.0);
+
+        Self {
+            threshold: clamped_threshold,
+            name: "coherence_sensor".to_string(),
+        }
+    }
+
+    /// Analyze two channels for cross-channel coherence anomalies
+    ///
+    /// # Arguments
+    /// * `channel_a` - First channel samples as numpy array (f64)
+    /// * `channel_b` - Second channel samples as numpy array (f64)
+    /// * `sample_rate` - Sample rate in Hz
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    pub fn analyze(
+        &self,
+        channel_a: PyReadonlyArray1<'_, f64>,
+        channel_b: PyReadonlyArray1<'_, f64>,
+        sample_rate: u32,
+    ) -> PyResult<SensorResult> {
+        let a_slice = channel_a.as_slice()?;
+        let b_slice = channel_b.as_slice()?;
+
+        for slice in [a_slice, b_slice] {
+            if let Err(e) = validate_audio_input(slice, sample_rate) {
+                return Ok(SensorResult::new(
+                    self.name.clone(),
+                    Some(false),
+                    0.0,
+                    self.threshold,
+                    Some("validation_error".to_string()),
+                    Some(format!("Input validation failed: {}", e)),
+                ));
+            }
+        }
+
+        let authenticity_score = self.compute_coherence_score(a_slice, b_slice, sample_rate);
+
+        let passed = authenticity_score >= self.threshold;
+
+        let detail = if passed {
+            format!(
+                "Cross-channel coherence analysis passed (score: {:.2})",
+                authenticity_score
+            )
+        } else {
+            format!(
+                "Abnormal cross-channel coherence detected (score: {:.2})",
+                authenticity_score
+            )
+        };
+
+        Ok(SensorResult::new(
+            self.name.clone(),
+            Some(passed),
+            authenticity_score,
+            self.threshold,
+            if !passed {
+                Some("coherence_anomaly".to_string())
+            } else {
+                None
+            },
+            Some(detail),
+        ))
+    }
+
+    /// String representation for Python
+    fn __repr__(&self) -> String {
+        format!(
+            "CoherenceSensor(name='{}', threshold={})",
+            self.name, self.threshold
+        )
+    }
+}
+
+impl CoherenceSensor {
+    /// Compute cross-channel coherence authenticity score
+    fn compute_coherence_score(&self, a: &[f64], b: &[f64], sample_rate: u32) -> f64 {
+        let spectra = match welch_spectra(a, b, SEGMENT_SIZE, HOP_SIZE) {
+            Ok(spectra) => spectra,
+            Err(_) => return 0.5, // Neutral score for insufficient data
+        };
+
+        let coherence = magnitude_squared_coherence(&spectra);
+        if coherence.len() < 4 {
+            return 0.5;
+        }
+
+        let frequencies = frequency_bins(SEGMENT_SIZE, sample_rate);
+
+        let plateau_score = self.compute_plateau_score(&coherence);
+        let cutoff_score = self.compute_cutoff_score(&coherence, &frequencies);
+        let decay_score = self.compute_decay_score(&coherence);
+
+        (0.4 * plateau_score + 0.3 * cutoff_score + 0.3 * decay_score).clamp(0.0, 1.0)
+    }
+
+    /// Score the fraction of bins stuck at near-perfect coherence
+    ///
+    /// Synthetic/upsampled content is often near-perfectly coherent (~1.0)
+    /// across the entire band; genuine captures dip between formants.
+    fn compute_plateau_score(&self, coherence: &[f64]) -> f64 {
+        let plateau_fraction = coherence.iter().filter(|&&c| c > PLATEAU_THRESHOLD).count() as f64
+            / coherence.len() as f64;
+
+        if plateau_fraction > 0.85 {
+            ((1.0 - plateau_fraction) / 0.15).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Score for an abrupt coherence collapse at a synthesis cutoff
+    ///
+    /// Finds the steepest single-bin drop in coherence and flags it if the
+    /// signal stays collapsed afterward -- the signature of an upsampling
+    /// or vocoder bandwidth cutoff rather than a gradual natural rolloff.
+    fn compute_cutoff_score(&self, coherence: &[f64], frequencies: &[f64]) -> f64 {
+        if coherence.len() < 8 {
+            return 1.0;
+        }
+
+        let mut steepest_drop = 0.0;
+        let mut steepest_idx = 0;
+        for i in 1..coherence.len() {
+            let drop = coherence[i - 1] - coherence[i];
+            if drop > steepest_drop {
+                steepest_drop = drop;
+                steepest_idx = i;
+            }
+        }
+
+        if steepest_drop < 0.5 {
+            return 1.0;
+        }
+
+        let tail = &coherence[steepest_idx..];
+        let tail_mean = tail.iter().sum::<f64>() / tail.len() as f64;
+
+        let cutoff_freq = frequencies.get(steepest_idx).copied().unwrap_or(0.0);
+        let nyquist = frequencies.last().copied().unwrap_or(1.0).max(1.0);
+
+        // A sharp drop that stays collapsed, well below Nyquist, is the
+        // common neural-vocoder/upsampling artifact
+        if tail_mean < 0.2 && cutoff_freq < 0.9 * nyquist {
+            (tail_mean / 0.2).clamp(0.0, 1.0) * 0.5
+        } else {
+            1.0
+        }
+    }
+
+    /// Score the natural decay/variability of coherence across frequency
+    ///
+    /// Natural acoustic captures show coherence decaying with frequency
+    /// and dipping between formants (moderate spread); a flat coherence
+    /// curve (whether high or low throughout) suggests synthetic content.
+    fn compute_decay_score(&self, coherence: &[f64]) -> f64 {
+        let mean = coherence.iter().sum::<f64>() / coherence.len() as f64;
+        let variance =
+            coherence.iter().map(|&c| (c - mean).powi(2)).sum::<f64>() / coherence.len() as f64;
+        let std = variance.sqrt();
+
+        if std < 0.03 {
+            (std / 0.03).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coherence_sensor_creation() {
+        let sensor = CoherenceSensor::new(None);
+        assert_eq!(sensor.name, "coherence_sensor");
+        assert!((sensor.threshold - DEFAULT_THRESHOLD).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_coherence_sensor_custom_threshold() {
+        let sensor = CoherenceSensor::new(Some(0.8));
+        assert!((sensor.threshold - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_plateau_score_flags_perfectly_coherent_band() {
+        let sensor = CoherenceSensor::new(None);
+        let coherence = vec![0.999; 64];
+        let score = sensor.compute_plateau_score(&coherence);
+        assert!(score < 0.5);
+    }
+
+    #[test]
+    fn test_decay_score_rewards_natural_variability() {
+        let sensor = CoherenceSensor::new(None);
+        let coherence: Vec<f64> = (0..64)
+            .map(|i| 0.5 + 0.3 * (i as f64 * 0.2).sin())
+            .collect();
+        let score = sensor.compute_decay_score(&coherence);
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -4,9 +4,21 @@
 //! - `vacuum`: Source-Filter Model (SFM) analysis
 //! - `phase`: Multi-Phase Coherence (MPC) detection
 //! - `articulation`: Speech articulation pattern analysis
+//! - `pitch`: F0/jitter pitch-stability detection
+//! - `coherence`: Cross-channel magnitude-squared coherence detection
+//! - `periodicity`: Autocorrelation power-spectrum buzz detection
+//! - `chroma`: Chromagram-based tonal-stationarity detection
+//! - `prosody`: Pitch-contour naturalness (jitter/micro-prosody) detection
+//! - `tonality`: Key/mode coherence detection via chromagram profile correlation
 //! - `result`: Standardized sensor result structure
 
 pub mod articulation;
+pub mod chroma;
+pub mod coherence;
+pub mod periodicity;
 pub mod phase;
+pub mod pitch;
+pub mod prosody;
 pub mod result;
+pub mod tonality;
 pub mod vacuum;
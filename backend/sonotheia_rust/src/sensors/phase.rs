@@ -8,7 +8,10 @@ use numpy::PyReadonlyArray1;
 use pyo3::prelude::*;
 
 use crate::sensors::result::SensorResult;
-use crate::utils::audio::{apply_hamming_window, calculate_rms, frame_audio, validate_audio_input};
+use crate::utils::audio::{
+    apply_hamming_window, calculate_rms, downmix_interleaved, frame_audio, resample_to,
+    validate_audio_input, CANONICAL_SAMPLE_RATE,
+};
 use crate::utils::fft::{compute_fft, phase_spectrum};
 
 /// Default threshold for phase sensor pass/fail decision
@@ -70,32 +73,80 @@ impl PhaseSensor {
     /// Analyze audio data for phase coherence anomalies
     ///
     /// # Arguments
-    /// * `audio` - Audio samples as numpy array (f64)
+    /// * `audio` - Audio samples as numpy array (f64), interleaved across
+    ///   channels when `channels` > 1
     /// * `sample_rate` - Sample rate in Hz
+    /// * `channels` - Number of interleaved channels in `audio`, default 1
+    ///   (mono); multichannel input is averaged down to mono before analysis
     ///
     /// # Returns
     /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (audio, sample_rate, channels=None))]
     pub fn analyze(
         &self,
         audio: PyReadonlyArray1<'_, f64>,
         sample_rate: u32,
+        channels: Option<u32>,
     ) -> PyResult<SensorResult> {
         let audio_slice = audio.as_slice()?;
+        let mono = downmix_interleaved(audio_slice, channels);
+        Ok(self.analyze_samples(&mono, sample_rate))
+    }
+
+    /// Load, resample, and analyze an audio file in one call
+    ///
+    /// # Arguments
+    /// * `path` - Path to an audio file decodable by `utils::io`
+    /// * `target_rate` - Sample rate to resample to before analysis, default 16000
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (path, target_rate=None))]
+    pub fn analyze_file(&self, path: String, target_rate: Option<u32>) -> PyResult<SensorResult> {
+        let rate = target_rate.unwrap_or(crate::utils::io::DEFAULT_TARGET_RATE);
+        match crate::utils::io::load_and_prepare(&path, rate) {
+            Ok(audio) => Ok(self.analyze_samples(&audio, rate)),
+            Err(e) => Ok(SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("io_error".to_string()),
+                Some(format!("Failed to load audio file: {}", e)),
+            )),
+        }
+    }
+
+    /// String representation for Python
+    fn __repr__(&self) -> String {
+        format!(
+            "PhaseSensor(name='{}', threshold={})",
+            self.name, self.threshold
+        )
+    }
+}
 
+impl PhaseSensor {
+    /// Analyze a raw sample slice, shared by `analyze` and `analyze_file`
+    fn analyze_samples(&self, audio_slice: &[f64], sample_rate: u32) -> SensorResult {
         // Validate input
         if let Err(e) = validate_audio_input(audio_slice, sample_rate) {
-            return Ok(SensorResult::new(
+            return SensorResult::new(
                 self.name.clone(),
                 Some(false),
                 0.0,
                 self.threshold,
                 Some("validation_error".to_string()),
                 Some(format!("Input validation failed: {}", e)),
-            ));
+            );
         }
 
+        // Resample to the canonical rate so the sensor's frame/hop
+        // constants apply exactly regardless of the input sample rate
+        let resampled = resample_to(audio_slice, sample_rate, CANONICAL_SAMPLE_RATE);
+
         // Perform analysis
-        let authenticity_score = self.compute_phase_coherence_score(audio_slice, sample_rate);
+        let authenticity_score = self.compute_phase_coherence_score(&resampled);
 
         // Determine pass/fail
         let passed = authenticity_score >= self.threshold;
@@ -112,7 +163,7 @@ impl PhaseSensor {
             )
         };
 
-        Ok(SensorResult::new(
+        SensorResult::new(
             self.name.clone(),
             Some(passed),
             authenticity_score,
@@ -123,24 +174,14 @@ impl PhaseSensor {
                 None
             },
             Some(detail),
-        ))
-    }
-
-    /// String representation for Python
-    fn __repr__(&self) -> String {
-        format!(
-            "PhaseSensor(name='{}', threshold={})",
-            self.name, self.threshold
         )
     }
-}
-
-impl PhaseSensor {
     /// Compute phase coherence authenticity score
-    fn compute_phase_coherence_score(&self, audio: &[f64], sample_rate: u32) -> f64 {
-        // Adjust frame parameters based on sample rate
-        let frame_size = (sample_rate as usize * FRAME_SIZE) / 16000;
-        let hop_size = (sample_rate as usize * HOP_SIZE) / 16000;
+    fn compute_phase_coherence_score(&self, audio: &[f64]) -> f64 {
+        // Audio has already been resampled to `CANONICAL_SAMPLE_RATE`, so
+        // the frame/hop constants apply exactly
+        let frame_size = FRAME_SIZE;
+        let hop_size = HOP_SIZE;
 
         // Frame the audio
         let frames = frame_audio(audio, frame_size, hop_size);
@@ -173,7 +214,8 @@ impl PhaseSensor {
 
         // Compute phase coherence metrics
         let coherence_score = self.compute_cross_frame_coherence(&phase_spectra);
-        let derivative_score = self.compute_phase_derivative_score(&phase_spectra);
+        let derivative_score =
+            self.compute_phase_derivative_score(&phase_spectra, hop_size, frame_size);
         let randomness_score = self.compute_phase_randomness_score(&phase_spectra);
 
         // Combined score
@@ -243,52 +285,124 @@ impl PhaseSensor {
         }
     }
 
-    /// Compute phase derivative (instantaneous frequency) score
-    fn compute_phase_derivative_score(&self, phase_spectra: &[Vec<f64>]) -> f64 {
-        if phase_spectra.len() < 3 {
+    /// Compute phase-vocoder instantaneous-frequency and group-delay score
+    ///
+    /// A raw second difference of wrapped phases conflates the
+    /// deterministic per-bin phase advance with real anomalies. Instead,
+    /// for bin `k` the expected phase advance per hop is
+    /// `2*pi*k*hop_size/n_fft`; subtracting this from the measured
+    /// inter-frame phase difference and wrapping the residual to
+    /// `[-pi, pi]` isolates the true instantaneous-frequency deviation.
+    /// Synthetic/vocoded audio tends to stick exactly to bin centers
+    /// (residual ~ 0) with abnormally low variance, while natural speech
+    /// carries structured deviation around it.
+    ///
+    /// Group delay -- the negative derivative of unwrapped phase across
+    /// adjacent frequency bins within a frame -- is combined alongside it:
+    /// natural speech shows structured ripple around formants, while
+    /// smoothed synthesis flattens that ripple.
+    fn compute_phase_derivative_score(
+        &self,
+        phase_spectra: &[Vec<f64>],
+        hop_size: usize,
+        n_fft: usize,
+    ) -> f64 {
+        if phase_spectra.len() < 2 || n_fft == 0 {
             return 0.5;
         }
 
-        let mut continuity_scores: Vec<f64> = Vec::new();
+        let mut residuals: Vec<f64> = Vec::new();
 
-        // Check phase continuity (second derivative)
-        for i in 2..phase_spectra.len() {
-            let prev2 = &phase_spectra[i - 2];
-            let prev1 = &phase_spectra[i - 1];
+        for i in 1..phase_spectra.len() {
+            let prev = &phase_spectra[i - 1];
             let curr = &phase_spectra[i];
 
-            let min_len = prev2.len().min(prev1.len()).min(curr.len());
+            let min_len = prev.len().min(curr.len());
             if min_len < 10 {
                 continue;
             }
 
-            // Second derivative of phase (acceleration)
-            let accelerations: Vec<f64> = (0..min_len)
-                .map(|j| {
-                    let d1 = self.wrap_phase(prev1[j] - prev2[j]);
-                    let d2 = self.wrap_phase(curr[j] - prev1[j]);
-                    (d2 - d1).abs()
-                })
+            for k in 0..min_len {
+                let expected_advance =
+                    2.0 * std::f64::consts::PI * k as f64 * hop_size as f64 / n_fft as f64;
+                let measured_diff = self.wrap_phase(curr[k] - prev[k]);
+                let residual = self.wrap_phase(measured_diff - expected_advance);
+                residuals.push(residual);
+            }
+        }
+
+        let if_score = self.compute_instantaneous_frequency_score(&residuals);
+        let gd_score = self.compute_group_delay_score(phase_spectra);
+
+        (0.5 * if_score + 0.5 * gd_score).clamp(0.0, 1.0)
+    }
+
+    /// Score the variance of instantaneous-frequency residuals
+    fn compute_instantaneous_frequency_score(&self, residuals: &[f64]) -> f64 {
+        if residuals.is_empty() {
+            return 0.5;
+        }
+
+        let mean = residuals.iter().sum::<f64>() / residuals.len() as f64;
+        let variance =
+            residuals.iter().map(|&r| (r - mean).powi(2)).sum::<f64>() / residuals.len() as f64;
+
+        // Bin-locked instantaneous frequency (residual variance near zero)
+        // is a hallmark of vocoded/resynthesized audio; excessive variance
+        // suggests noise or heavy manipulation.
+        if variance < 0.05 {
+            (variance / 0.05).clamp(0.0, 1.0) * 0.5
+        } else if variance > 1.5 {
+            (1.5 / variance).clamp(0.0, 1.0) * 0.6
+        } else {
+            1.0
+        }
+    }
+
+    /// Score the spread of group delay (unwrapped phase slope across bins)
+    fn compute_group_delay_score(&self, phase_spectra: &[Vec<f64>]) -> f64 {
+        let mut spreads: Vec<f64> = Vec::new();
+
+        for phases in phase_spectra {
+            if phases.len() < 10 {
+                continue;
+            }
+
+            // Unwrap phase across frequency bins within this frame
+            let mut unwrapped = Vec::with_capacity(phases.len());
+            unwrapped.push(phases[0]);
+            for k in 1..phases.len() {
+                let diff = self.wrap_phase(phases[k] - phases[k - 1]);
+                unwrapped.push(unwrapped[k - 1] + diff);
+            }
+
+            let group_delay: Vec<f64> = (0..unwrapped.len() - 1)
+                .map(|k| -(unwrapped[k + 1] - unwrapped[k]))
                 .collect();
 
-            let mean_accel = accelerations.iter().sum::<f64>() / accelerations.len() as f64;
-            continuity_scores.push(mean_accel);
+            if group_delay.is_empty() {
+                continue;
+            }
+
+            let mean = group_delay.iter().sum::<f64>() / group_delay.len() as f64;
+            let variance = group_delay.iter().map(|&g| (g - mean).powi(2)).sum::<f64>()
+                / group_delay.len() as f64;
+            spreads.push(variance);
         }
 
-        if continuity_scores.is_empty() {
+        if spreads.is_empty() {
             return 0.5;
         }
 
-        let mean_continuity =
-            continuity_scores.iter().sum::<f64>() / continuity_scores.len() as f64;
+        let mean_spread = spreads.iter().sum::<f64>() / spreads.len() as f64;
 
-        // Natural speech has moderate phase acceleration
-        // Very low acceleration suggests synthetic smoothness
-        // Very high acceleration suggests discontinuities
-        if mean_continuity < 0.1 {
-            (mean_continuity / 0.1).min(1.0) * 0.5
-        } else if mean_continuity > 2.0 {
-            (2.0 / mean_continuity).min(1.0) * 0.6
+        // Natural speech shows structured group-delay ripple around
+        // formants; flattened synthesis collapses this spread, while
+        // extreme spread suggests noise.
+        if mean_spread < 0.1 {
+            (mean_spread / 0.1).clamp(0.0, 1.0) * 0.5
+        } else if mean_spread > 3.0 {
+            (3.0 / mean_spread).clamp(0.0, 1.0) * 0.6
         } else {
             1.0
         }
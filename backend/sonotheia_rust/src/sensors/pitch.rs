@@ -0,0 +1,343 @@
+//! Pitch Sensor - F0/Jitter Stability Detection
+//!
+//! Detects synthetic audio by tracking cycle-to-cycle pitch perturbation.
+//! Natural phonation carries a small but nonzero jitter in its fundamental
+//! frequency; many TTS/vocoder outputs instead produce an unnaturally flat
+//! (or wildly unstable) F0 track.
+
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+
+use crate::sensors::result::SensorResult;
+use crate::utils::audio::{
+    apply_hamming_window, calculate_rms, downmix_interleaved, frame_audio, resample_to,
+    validate_audio_input, CANONICAL_SAMPLE_RATE,
+};
+use crate::utils::lpc::autocorrelation;
+
+/// Default threshold for pitch sensor pass/fail decision
+const DEFAULT_THRESHOLD: f64 = 0.6;
+
+/// Frame size in samples (32ms at 16kHz)
+const FRAME_SIZE: usize = 512;
+
+/// Hop size in samples (16ms at 16kHz)
+const HOP_SIZE: usize = 256;
+
+/// Minimum fundamental frequency considered (Hz)
+const MIN_VOICE_HZ: f64 = 50.0;
+
+/// Maximum fundamental frequency considered (Hz)
+const MAX_VOICE_HZ: f64 = 500.0;
+
+/// Fraction of `r(0)` a peak must clear to count as voiced
+const VOICING_CONFIDENCE: f64 = 0.3;
+
+/// Pitch Sensor for F0/jitter stability detection
+///
+/// This sensor tracks the fundamental frequency frame-by-frame via
+/// time-domain autocorrelation and scores the voiced track's
+/// cycle-to-cycle variability. Synthetic audio often exhibits:
+///
+/// - An unnaturally flat F0 track (near-zero jitter)
+/// - Wildly erratic pitch jumps (heavy manipulation or tracking failure)
+///
+/// # Example
+/// ```python
+/// from sonotheia_rust import PitchSensor
+///
+/// sensor = PitchSensor()
+/// result = sensor.analyze(audio_data, 16000)
+/// print(f"Passed: {result.passed}, Score: {result.value}")
+/// ```
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct PitchSensor {
+    /// Detection threshold (0.0-1.0)
+    #[pyo3(get, set)]
+    pub threshold: f64,
+
+    /// Sensor name identifier
+    #[pyo3(get)]
+    pub name: String,
+}
+
+#[pymethods]
+impl PitchSensor {
+    /// Create a new PitchSensor with optional threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Detection threshold (0.0-1.0), default 0.6
+    #[new]
+    #[pyo3(signature = (threshold=None))]
+    pub fn new(threshold: Option<f64>) -> Self {
+        let threshold_value = threshold.unwrap_or(DEFAULT_THRESHOLD);
+        let clamped_threshold = threshold_value.clamp(0.0, 1.0);
+
+        Self {
+            threshold: clamped_threshold,
+            name: "pitch_sensor".to_string(),
+        }
+    }
+
+    /// Analyze audio data for pitch-stability anomalies
+    ///
+    /// # Arguments
+    /// * `audio` - Audio samples as numpy array (f64), interleaved across
+    ///   channels when `channels` > 1
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `channels` - Number of interleaved channels in `audio`, default 1
+    ///   (mono); multichannel input is averaged down to mono before analysis
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (audio, sample_rate, channels=None))]
+    pub fn analyze(
+        &self,
+        audio: PyReadonlyArray1<'_, f64>,
+        sample_rate: u32,
+        channels: Option<u32>,
+    ) -> PyResult<SensorResult> {
+        let audio_slice = audio.as_slice()?;
+        let mono = downmix_interleaved(audio_slice, channels);
+        Ok(self.analyze_samples(&mono, sample_rate))
+    }
+
+    /// Load, resample, and analyze an audio file in one call
+    ///
+    /// # Arguments
+    /// * `path` - Path to an audio file decodable by `utils::io`
+    /// * `target_rate` - Sample rate to resample to before analysis, default 16000
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (path, target_rate=None))]
+    pub fn analyze_file(&self, path: String, target_rate: Option<u32>) -> PyResult<SensorResult> {
+        let rate = target_rate.unwrap_or(crate::utils::io::DEFAULT_TARGET_RATE);
+        match crate::utils::io::load_and_prepare(&path, rate) {
+            Ok(audio) => Ok(self.analyze_samples(&audio, rate)),
+            Err(e) => Ok(SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("io_error".to_string()),
+                Some(format!("Failed to load audio file: {}", e)),
+            )),
+        }
+    }
+
+    /// String representation for Python
+    fn __repr__(&self) -> String {
+        format!(
+            "PitchSensor(name='{}', threshold={})",
+            self.name, self.threshold
+        )
+    }
+}
+
+impl PitchSensor {
+    /// Analyze a raw sample slice, shared by `analyze` and `analyze_file`
+    fn analyze_samples(&self, audio_slice: &[f64], sample_rate: u32) -> SensorResult {
+        // Validate input
+        if let Err(e) = validate_audio_input(audio_slice, sample_rate) {
+            return SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("validation_error".to_string()),
+                Some(format!("Input validation failed: {}", e)),
+            );
+        }
+
+        // Perform analysis
+        // Resample to the canonical rate so the sensor's frame/hop
+        // constants apply exactly regardless of the input sample rate
+        let resampled = resample_to(audio_slice, sample_rate, CANONICAL_SAMPLE_RATE);
+
+        let authenticity_score =
+            self.compute_pitch_variability_score(&resampled, CANONICAL_SAMPLE_RATE);
+
+        // Determine pass/fail
+        let passed = authenticity_score >= self.threshold;
+
+        let detail = if passed {
+            format!(
+                "Pitch stability analysis passed (score: {:.2})",
+                authenticity_score
+            )
+        } else {
+            format!(
+                "Abnormal pitch-stability pattern detected (score: {:.2})",
+                authenticity_score
+            )
+        };
+
+        SensorResult::new(
+            self.name.clone(),
+            Some(passed),
+            authenticity_score,
+            self.threshold,
+            if !passed {
+                Some("pitch_anomaly".to_string())
+            } else {
+                None
+            },
+            Some(detail),
+        )
+    }
+    /// Compute pitch-variability authenticity score
+    fn compute_pitch_variability_score(&self, audio: &[f64], sample_rate: u32) -> f64 {
+        // Audio has already been resampled to `CANONICAL_SAMPLE_RATE`, so
+        // the frame/hop constants apply exactly
+        let frame_size = FRAME_SIZE;
+        let hop_size = HOP_SIZE;
+
+        let frames = frame_audio(audio, frame_size, hop_size);
+
+        if frames.len() < 4 {
+            return 0.5; // Neutral score for insufficient data
+        }
+
+        let mut f0_track: Vec<f64> = Vec::with_capacity(frames.len());
+
+        for frame in &frames {
+            let windowed = apply_hamming_window(frame);
+
+            // Skip silent frames
+            if calculate_rms(&windowed) < 1e-6 {
+                continue;
+            }
+
+            if let Some(f0) = self.estimate_f0(&windowed, sample_rate) {
+                f0_track.push(f0);
+            }
+        }
+
+        if f0_track.len() < 4 {
+            return 0.5; // Not enough voiced frames to judge stability
+        }
+
+        self.compute_jitter_score(&f0_track)
+    }
+
+    /// Estimate F0 of a single windowed frame via zero-crossing autocorrelation
+    ///
+    /// Computes `r(τ)` over the voice-range lags, normalizes by `r(0)`,
+    /// finds the first lag where `r` crosses below zero (end of the
+    /// central lobe), then locates the maximum peak after that crossing.
+    /// Returns `None` if the frame is unvoiced (peak below
+    /// `VOICING_CONFIDENCE * r(0)`).
+    fn estimate_f0(&self, frame: &[f64], sample_rate: u32) -> Option<f64> {
+        let sr = sample_rate as f64;
+        let min_lag = (sr / MAX_VOICE_HZ).floor().max(1.0) as usize;
+        let max_lag = (sr / MIN_VOICE_HZ).ceil() as usize;
+
+        if max_lag >= frame.len() || min_lag >= max_lag {
+            return None;
+        }
+
+        let r = autocorrelation(frame, max_lag);
+        if r[0].abs() < 1e-12 {
+            return None;
+        }
+
+        // Find the first lag past min_lag where r crosses below zero
+        let mut crossing = None;
+        for lag in min_lag..max_lag {
+            if r[lag] < 0.0 {
+                crossing = Some(lag);
+                break;
+            }
+        }
+        let search_start = crossing.unwrap_or(min_lag);
+
+        // Locate the maximum peak after the crossing
+        let mut peak_lag = search_start;
+        let mut peak_value = r[search_start];
+        for (lag, &value) in r.iter().enumerate().take(max_lag + 1).skip(search_start) {
+            if value > peak_value {
+                peak_value = value;
+                peak_lag = lag;
+            }
+        }
+
+        if peak_lag == 0 || peak_value < VOICING_CONFIDENCE * r[0] {
+            return None;
+        }
+
+        Some(sr / peak_lag as f64)
+    }
+
+    /// Score the voiced F0 track's cycle-to-cycle jitter
+    ///
+    /// Thin wrapper around `utils::pitch::jitter_naturalness_score`, shared
+    /// with `ProsodySensor` so the banded natural-range scoring can't drift
+    /// between the two sensors.
+    fn compute_jitter_score(&self, f0_track: &[f64]) -> f64 {
+        crate::utils::pitch::jitter_naturalness_score(f0_track)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pitch_sensor_creation() {
+        let sensor = PitchSensor::new(None);
+        assert_eq!(sensor.name, "pitch_sensor");
+        assert!((sensor.threshold - DEFAULT_THRESHOLD).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_pitch_sensor_custom_threshold() {
+        let sensor = PitchSensor::new(Some(0.8));
+        assert!((sensor.threshold - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_pitch_sensor_threshold_clamping() {
+        let sensor = PitchSensor::new(Some(1.5));
+        assert!((sensor.threshold - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_estimate_f0_on_sine() {
+        let sensor = PitchSensor::new(None);
+        let sample_rate = 16000u32;
+        let freq = 150.0;
+        let frame: Vec<f64> = (0..1024)
+            .map(|i| (2.0 * std::f64::consts::PI * freq * i as f64 / sample_rate as f64).sin())
+            .collect();
+
+        let f0 = sensor
+            .estimate_f0(&frame, sample_rate)
+            .expect("should detect pitch on a clean sine");
+        assert!((f0 - freq).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_estimate_f0_silent_frame() {
+        let sensor = PitchSensor::new(None);
+        let frame = vec![0.0; 512];
+        assert!(sensor.estimate_f0(&frame, 16000).is_none());
+    }
+
+    #[test]
+    fn test_jitter_score_flat_track_scores_low() {
+        let sensor = PitchSensor::new(None);
+        let track = vec![150.0; 10];
+        let score = sensor.compute_jitter_score(&track);
+        assert!(score < 0.6);
+    }
+
+    #[test]
+    fn test_jitter_score_natural_variation_scores_high() {
+        let sensor = PitchSensor::new(None);
+        let track = vec![150.0, 151.5, 149.0, 150.8, 149.5, 150.2];
+        let score = sensor.compute_jitter_score(&track);
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+}
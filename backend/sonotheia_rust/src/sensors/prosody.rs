@@ -0,0 +1,299 @@
+//! Prosody Sensor - Pitch Contour Naturalness Detection
+//!
+//! Detects synthetic speech by the naturalness of its pitch contour. A
+//! frame-by-frame F0 track is built via autocorrelation, then scored on
+//! period-to-period jitter and the smoothness/variance of the contour --
+//! TTS output often produces contours that are either unnaturally flat or
+//! quantized (stair-stepped between a small set of F0 values).
+
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+
+use crate::sensors::result::SensorResult;
+use crate::utils::audio::{
+    apply_hamming_window, calculate_rms, downmix_interleaved, find_fundamental_frequency,
+    frame_audio, resample_to, validate_audio_input, CANONICAL_SAMPLE_RATE,
+};
+
+/// Default threshold for prosody sensor pass/fail decision
+const DEFAULT_THRESHOLD: f64 = 0.6;
+
+/// Frame size in samples (32ms at 16kHz)
+const FRAME_SIZE: usize = 512;
+
+/// Hop size in samples (16ms at 16kHz)
+const HOP_SIZE: usize = 256;
+
+/// Prosody Sensor for pitch-contour naturalness detection
+///
+/// # Example
+/// ```python
+/// from sonotheia_rust import ProsodySensor
+///
+/// sensor = ProsodySensor()
+/// result = sensor.analyze(audio_data, 16000)
+/// print(f"Passed: {result.passed}, Score: {result.value}")
+/// ```
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct ProsodySensor {
+    /// Detection threshold (0.0-1.0)
+    #[pyo3(get, set)]
+    pub threshold: f64,
+
+    /// Sensor name identifier
+    #[pyo3(get)]
+    pub name: String,
+}
+
+#[pymethods]
+impl ProsodySensor {
+    /// Create a new ProsodySensor with optional threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Detection threshold (0.0-1.0), default 0.6
+    #[new]
+    #[pyo3(signature = (threshold=None))]
+    pub fn new(threshold: Option<f64>) -> Self {
+        let threshold_value = threshold.unwrap_or(DEFAULT_THRESHOLD);
+        let clamped_threshold = threshold_value.clamp(0.0, 1.0);
+
+        Self {
+            threshold: clamped_threshold,
+            name: "prosody_sensor".to_string(),
+        }
+    }
+
+    /// Analyze audio data for pitch-contour naturalness anomalies
+    ///
+    /// # Arguments
+    /// * `audio` - Audio samples as numpy array (f64), interleaved across
+    ///   channels when `channels` > 1
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `channels` - Number of interleaved channels in `audio`, default 1
+    ///   (mono); multichannel input is averaged down to mono before analysis
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (audio, sample_rate, channels=None))]
+    pub fn analyze(
+        &self,
+        audio: PyReadonlyArray1<'_, f64>,
+        sample_rate: u32,
+        channels: Option<u32>,
+    ) -> PyResult<SensorResult> {
+        let audio_slice = audio.as_slice()?;
+        let mono = downmix_interleaved(audio_slice, channels);
+        Ok(self.analyze_samples(&mono, sample_rate))
+    }
+
+    /// Load, resample, and analyze an audio file in one call
+    ///
+    /// # Arguments
+    /// * `path` - Path to an audio file decodable by `utils::io`
+    /// * `target_rate` - Sample rate to resample to before analysis, default 16000
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (path, target_rate=None))]
+    pub fn analyze_file(&self, path: String, target_rate: Option<u32>) -> PyResult<SensorResult> {
+        let rate = target_rate.unwrap_or(crate::utils::io::DEFAULT_TARGET_RATE);
+        match crate::utils::io::load_and_prepare(&path, rate) {
+            Ok(audio) => Ok(self.analyze_samples(&audio, rate)),
+            Err(e) => Ok(SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("io_error".to_string()),
+                Some(format!("Failed to load audio file: {}", e)),
+            )),
+        }
+    }
+
+    /// String representation for Python
+    fn __repr__(&self) -> String {
+        format!(
+            "ProsodySensor(name='{}', threshold={})",
+            self.name, self.threshold
+        )
+    }
+}
+
+impl ProsodySensor {
+    /// Analyze a raw sample slice, shared by `analyze` and `analyze_file`
+    fn analyze_samples(&self, audio_slice: &[f64], sample_rate: u32) -> SensorResult {
+        // Validate input
+        if let Err(e) = validate_audio_input(audio_slice, sample_rate) {
+            return SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("validation_error".to_string()),
+                Some(format!("Input validation failed: {}", e)),
+            );
+        }
+
+        // Perform analysis
+        // Resample to the canonical rate so the sensor's frame/hop
+        // constants apply exactly regardless of the input sample rate
+        let resampled = resample_to(audio_slice, sample_rate, CANONICAL_SAMPLE_RATE);
+
+        let authenticity_score = self.compute_prosody_score(&resampled, CANONICAL_SAMPLE_RATE);
+
+        // Determine pass/fail
+        let passed = authenticity_score >= self.threshold;
+
+        let detail = if passed {
+            format!(
+                "Prosody naturalness analysis passed (score: {:.2})",
+                authenticity_score
+            )
+        } else {
+            format!(
+                "Unnatural pitch contour detected (score: {:.2})",
+                authenticity_score
+            )
+        };
+
+        SensorResult::new(
+            self.name.clone(),
+            Some(passed),
+            authenticity_score,
+            self.threshold,
+            if !passed {
+                Some("prosody_anomaly".to_string())
+            } else {
+                None
+            },
+            Some(detail),
+        )
+    }
+
+    /// Compute pitch-contour authenticity score
+    fn compute_prosody_score(&self, audio: &[f64], sample_rate: u32) -> f64 {
+        // Audio has already been resampled to `CANONICAL_SAMPLE_RATE`, so
+        // the frame/hop constants apply exactly
+        let frame_size = FRAME_SIZE;
+        let hop_size = HOP_SIZE;
+
+        let frames = frame_audio(audio, frame_size, hop_size);
+
+        if frames.len() < 4 {
+            return 0.5; // Neutral score for insufficient data
+        }
+
+        let mut f0_contour: Vec<f64> = Vec::with_capacity(frames.len());
+
+        for frame in &frames {
+            let windowed = apply_hamming_window(frame);
+
+            // Skip silent frames
+            if calculate_rms(&windowed) < 1e-6 {
+                continue;
+            }
+
+            if let Some(f0) = find_fundamental_frequency(&windowed, sample_rate) {
+                f0_contour.push(f0);
+            }
+        }
+
+        if f0_contour.len() < 4 {
+            return 0.5; // Not enough voiced frames to judge prosody
+        }
+
+        let jitter_score = self.compute_jitter_score(&f0_contour);
+        let smoothness_score = self.compute_smoothness_score(&f0_contour);
+
+        (0.5 * jitter_score + 0.5 * smoothness_score).clamp(0.0, 1.0)
+    }
+
+    /// Score the contour's period-to-period jitter
+    ///
+    /// Thin wrapper around `utils::pitch::jitter_naturalness_score`, shared
+    /// with `PitchSensor` so the banded natural-range scoring can't drift
+    /// between the two sensors.
+    fn compute_jitter_score(&self, f0_contour: &[f64]) -> f64 {
+        crate::utils::pitch::jitter_naturalness_score(f0_contour)
+    }
+
+    /// Score the contour's overall smoothness/variance
+    ///
+    /// Uses the coefficient of variation (standard deviation / mean F0):
+    /// below 1% the score falls linearly toward 0, otherwise it is 1.0.
+    /// This flags flat contours; a stair-stepped contour whose steps
+    /// spread over more than 1% of mean F0 is not penalized.
+    fn compute_smoothness_score(&self, f0_contour: &[f64]) -> f64 {
+        let mean_f0 = f0_contour.iter().sum::<f64>() / f0_contour.len() as f64;
+        if mean_f0 < 1e-9 {
+            return 0.5;
+        }
+
+        let variance = f0_contour
+            .iter()
+            .map(|&f0| (f0 - mean_f0).powi(2))
+            .sum::<f64>()
+            / f0_contour.len() as f64;
+        let coefficient_of_variation = variance.sqrt() / mean_f0;
+
+        // Natural speech prosody typically varies ~1-15% of mean F0 across
+        // an utterance; near-zero variation suggests a flat/quantized
+        // contour (synthetic)
+        if coefficient_of_variation < 0.01 {
+            (coefficient_of_variation / 0.01).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_prosody_sensor_creation() {
+        let sensor = ProsodySensor::new(None);
+        assert_eq!(sensor.name, "prosody_sensor");
+        assert!((sensor.threshold - DEFAULT_THRESHOLD).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_prosody_sensor_custom_threshold() {
+        let sensor = ProsodySensor::new(Some(0.8));
+        assert!((sensor.threshold - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_jitter_score_flat_contour_scores_low() {
+        let sensor = ProsodySensor::new(None);
+        let contour = vec![150.0; 10];
+        let score = sensor.compute_jitter_score(&contour);
+        assert!(score < 0.6);
+    }
+
+    #[test]
+    fn test_jitter_score_natural_variation_scores_high() {
+        let sensor = ProsodySensor::new(None);
+        let contour = vec![150.0, 151.5, 149.0, 150.8, 149.5, 150.2];
+        let score = sensor.compute_jitter_score(&contour);
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_smoothness_score_flags_flat_contour() {
+        let sensor = ProsodySensor::new(None);
+        let contour = vec![150.0; 20];
+        let score = sensor.compute_smoothness_score(&contour);
+        assert!(score < 0.5);
+    }
+
+    #[test]
+    fn test_smoothness_score_rewards_natural_spread() {
+        let sensor = ProsodySensor::new(None);
+        let contour: Vec<f64> = (0..20).map(|i| 150.0 + 10.0 * (i as f64 * 0.3).sin()).collect();
+        let score = sensor.compute_smoothness_score(&contour);
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+}
@@ -0,0 +1,415 @@
+//! Tonality Sensor - Key/Mode Coherence Detection
+//!
+//! Detects edits, splices, and some synthesis artifacts by the coherence of
+//! a clip's musical key. A frame-by-frame chromagram is averaged into a
+//! clip-level pitch-class profile, correlated against rotated major and
+//! minor key templates to find the best-matching key/mode, and then the
+//! per-frame chroma vectors are checked for how consistently they agree
+//! with that key -- spliced or synthesized audio often drifts between keys
+//! or loses tonal focus partway through a clip.
+
+use numpy::PyReadonlyArray1;
+use pyo3::prelude::*;
+
+use crate::sensors::result::SensorResult;
+use crate::utils::audio::{
+    apply_hamming_window, calculate_rms, downmix_interleaved, frame_audio, resample_to,
+    validate_audio_input, CANONICAL_SAMPLE_RATE,
+};
+use crate::utils::fft::{chromagram, compute_fft, frequency_bins, magnitude_spectrum, CHROMA_BINS};
+
+/// Default threshold for tonality sensor pass/fail decision
+const DEFAULT_THRESHOLD: f64 = 0.6;
+
+/// STFT window size in samples (512ms at 16kHz) -- long for frequency resolution
+const FRAME_SIZE: usize = 8192;
+
+/// STFT hop size in samples (256ms at 16kHz)
+const HOP_SIZE: usize = 4096;
+
+/// Krumhansl-Kessler major-key pitch-class profile, starting at C
+const MAJOR_PROFILE: [f64; CHROMA_BINS] = [
+    6.35, 2.23, 3.48, 2.33, 4.38, 4.09, 2.52, 5.19, 2.39, 3.66, 2.29, 2.88,
+];
+
+/// Krumhansl-Kessler minor-key pitch-class profile, starting at C
+const MINOR_PROFILE: [f64; CHROMA_BINS] = [
+    6.33, 2.68, 3.52, 5.38, 2.60, 3.53, 2.54, 4.75, 3.98, 2.69, 3.34, 3.17,
+];
+
+/// Per-frame key-correlation below which a frame is considered tonally
+/// discordant with the clip's detected key
+const DISCORD_CORRELATION_THRESHOLD: f64 = 0.3;
+
+/// Tonality Sensor for key/mode coherence detection
+///
+/// # Example
+/// ```python
+/// from sonotheia_rust import TonalitySensor
+///
+/// sensor = TonalitySensor()
+/// result = sensor.analyze(audio_data, 16000)
+/// print(f"Passed: {result.passed}, Score: {result.value}")
+/// ```
+#[pyclass]
+#[derive(Debug, Clone)]
+pub struct TonalitySensor {
+    /// Detection threshold (0.0-1.0)
+    #[pyo3(get, set)]
+    pub threshold: f64,
+
+    /// Sensor name identifier
+    #[pyo3(get)]
+    pub name: String,
+}
+
+#[pymethods]
+impl TonalitySensor {
+    /// Create a new TonalitySensor with optional threshold
+    ///
+    /// # Arguments
+    /// * `threshold` - Detection threshold (0.0-1.0), default 0.6
+    #[new]
+    #[pyo3(signature = (threshold=None))]
+    pub fn new(threshold: Option<f64>) -> Self {
+        let threshold_value = threshold.unwrap_or(DEFAULT_THRESHOLD);
+        let clamped_threshold = threshold_value.clamp(0.0, 1.0);
+
+        Self {
+            threshold: clamped_threshold,
+            name: "tonality_sensor".to_string(),
+        }
+    }
+
+    /// Analyze audio data for key/mode coherence anomalies
+    ///
+    /// # Arguments
+    /// * `audio` - Audio samples as numpy array (f64), interleaved across
+    ///   channels when `channels` > 1
+    /// * `sample_rate` - Sample rate in Hz
+    /// * `channels` - Number of interleaved channels in `audio`, default 1
+    ///   (mono); multichannel input is averaged down to mono before analysis
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (audio, sample_rate, channels=None))]
+    pub fn analyze(
+        &self,
+        audio: PyReadonlyArray1<'_, f64>,
+        sample_rate: u32,
+        channels: Option<u32>,
+    ) -> PyResult<SensorResult> {
+        let audio_slice = audio.as_slice()?;
+        let mono = downmix_interleaved(audio_slice, channels);
+        Ok(self.analyze_samples(&mono, sample_rate))
+    }
+
+    /// Load, resample, and analyze an audio file in one call
+    ///
+    /// # Arguments
+    /// * `path` - Path to an audio file decodable by `utils::io`
+    /// * `target_rate` - Sample rate to resample to before analysis, default 16000
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (path, target_rate=None))]
+    pub fn analyze_file(&self, path: String, target_rate: Option<u32>) -> PyResult<SensorResult> {
+        let rate = target_rate.unwrap_or(crate::utils::io::DEFAULT_TARGET_RATE);
+        match crate::utils::io::load_and_prepare(&path, rate) {
+            Ok(audio) => Ok(self.analyze_samples(&audio, rate)),
+            Err(e) => Ok(SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("io_error".to_string()),
+                Some(format!("Failed to load audio file: {}", e)),
+            )),
+        }
+    }
+
+    /// String representation for Python
+    fn __repr__(&self) -> String {
+        format!(
+            "TonalitySensor(name='{}', threshold={})",
+            self.name, self.threshold
+        )
+    }
+}
+
+impl TonalitySensor {
+    /// Analyze a raw sample slice, shared by `analyze` and `analyze_file`
+    fn analyze_samples(&self, audio_slice: &[f64], sample_rate: u32) -> SensorResult {
+        // Validate input
+        if let Err(e) = validate_audio_input(audio_slice, sample_rate) {
+            return SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("validation_error".to_string()),
+                Some(format!("Input validation failed: {}", e)),
+            );
+        }
+
+        // Resample to the canonical rate so the sensor's frame/hop
+        // constants apply exactly regardless of the input sample rate
+        let resampled = resample_to(audio_slice, sample_rate, CANONICAL_SAMPLE_RATE);
+
+        let authenticity_score = self.compute_tonality_score(&resampled, CANONICAL_SAMPLE_RATE);
+
+        // Determine pass/fail
+        let passed = authenticity_score >= self.threshold;
+
+        let detail = if passed {
+            format!(
+                "Tonal key coherence passed (score: {:.2})",
+                authenticity_score
+            )
+        } else {
+            format!(
+                "Tonal key discontinuity detected (score: {:.2})",
+                authenticity_score
+            )
+        };
+
+        SensorResult::new(
+            self.name.clone(),
+            Some(passed),
+            authenticity_score,
+            self.threshold,
+            if !passed {
+                Some("tonality_anomaly".to_string())
+            } else {
+                None
+            },
+            Some(detail),
+        )
+    }
+
+    /// Compute key/mode-coherence authenticity score
+    fn compute_tonality_score(&self, audio: &[f64], sample_rate: u32) -> f64 {
+        // Audio has already been resampled to `CANONICAL_SAMPLE_RATE`, so
+        // the frame/hop constants apply exactly
+        let frame_size = FRAME_SIZE;
+        let hop_size = HOP_SIZE;
+
+        let frames = frame_audio(audio, frame_size, hop_size);
+
+        if frames.len() < 3 {
+            return 0.5; // Neutral score for insufficient data
+        }
+
+        let frequencies = frequency_bins(frame_size, sample_rate);
+
+        let mut chroma_vectors: Vec<[f64; CHROMA_BINS]> = Vec::with_capacity(frames.len());
+
+        for frame in &frames {
+            let windowed = apply_hamming_window(frame);
+
+            // Skip silent frames
+            if calculate_rms(&windowed) < 1e-6 {
+                continue;
+            }
+
+            if let Ok(fft_result) = compute_fft(&windowed) {
+                let magnitudes = magnitude_spectrum(&fft_result);
+                chroma_vectors.push(chromagram(&magnitudes, &frequencies));
+            }
+        }
+
+        if chroma_vectors.len() < 3 {
+            return 0.5; // Not enough voiced frames to judge key coherence
+        }
+
+        let mean_chroma = Self::mean_chroma(&chroma_vectors);
+        let (_key, _is_major, key_clarity) = Self::best_key(&mean_chroma);
+        let clarity_score = self.compute_key_clarity_score(key_clarity);
+        let coherence_score = self.compute_key_coherence_score(&chroma_vectors, &mean_chroma);
+
+        (0.5 * clarity_score + 0.5 * coherence_score).clamp(0.0, 1.0)
+    }
+
+    /// Average a set of chroma vectors into a single clip-level profile
+    fn mean_chroma(chroma_vectors: &[[f64; CHROMA_BINS]]) -> [f64; CHROMA_BINS] {
+        let mut mean = [0.0; CHROMA_BINS];
+        for chroma in chroma_vectors {
+            for (m, &c) in mean.iter_mut().zip(chroma.iter()) {
+                *m += c;
+            }
+        }
+        let n = chroma_vectors.len() as f64;
+        for value in mean.iter_mut() {
+            *value /= n;
+        }
+        mean
+    }
+
+    /// Pearson correlation between a chroma vector and a rotated key profile
+    fn profile_correlation(
+        chroma: &[f64; CHROMA_BINS],
+        profile: &[f64; CHROMA_BINS],
+        rotation: usize,
+    ) -> f64 {
+        let rotated: Vec<f64> = (0..CHROMA_BINS)
+            .map(|i| profile[(i + CHROMA_BINS - rotation) % CHROMA_BINS])
+            .collect();
+
+        let chroma_mean = chroma.iter().sum::<f64>() / CHROMA_BINS as f64;
+        let profile_mean = rotated.iter().sum::<f64>() / CHROMA_BINS as f64;
+
+        let mut numerator = 0.0;
+        let mut chroma_var = 0.0;
+        let mut profile_var = 0.0;
+        for i in 0..CHROMA_BINS {
+            let c_dev = chroma[i] - chroma_mean;
+            let p_dev = rotated[i] - profile_mean;
+            numerator += c_dev * p_dev;
+            chroma_var += c_dev * c_dev;
+            profile_var += p_dev * p_dev;
+        }
+
+        let denom = (chroma_var * profile_var).sqrt();
+        if denom < 1e-12 {
+            0.0
+        } else {
+            numerator / denom
+        }
+    }
+
+    /// Find the best-correlating (key, mode) rotation for a chroma vector
+    ///
+    /// Correlates the vector against all 12 rotations each of the major and
+    /// minor Krumhansl-Kessler profiles and returns the winning pitch class
+    /// (0 = C), whether it's major, and its correlation coefficient.
+    fn best_key(chroma: &[f64; CHROMA_BINS]) -> (usize, bool, f64) {
+        let mut best = (0usize, true, f64::MIN);
+
+        for rotation in 0..CHROMA_BINS {
+            let major_score = Self::profile_correlation(chroma, &MAJOR_PROFILE, rotation);
+            if major_score > best.2 {
+                best = (rotation, true, major_score);
+            }
+
+            let minor_score = Self::profile_correlation(chroma, &MINOR_PROFILE, rotation);
+            if minor_score > best.2 {
+                best = (rotation, false, minor_score);
+            }
+        }
+
+        best
+    }
+
+    /// Score how clearly a single key/mode stands out in the clip
+    ///
+    /// A clear tonal center correlates strongly with its key profile;
+    /// ambiguous or atonal material (including some synthesis artifacts)
+    /// correlates weakly with every rotation.
+    fn compute_key_clarity_score(&self, key_clarity: f64) -> f64 {
+        (key_clarity / DISCORD_CORRELATION_THRESHOLD.max(1e-9)).clamp(0.0, 1.0)
+    }
+
+    /// Score how consistently each frame agrees with the clip's detected key
+    ///
+    /// Spliced or synthesized audio often drifts between keys partway
+    /// through a clip; this measures the fraction of frames whose
+    /// correlation with the clip-level key stays above
+    /// `DISCORD_CORRELATION_THRESHOLD`.
+    fn compute_key_coherence_score(
+        &self,
+        chroma_vectors: &[[f64; CHROMA_BINS]],
+        mean_chroma: &[f64; CHROMA_BINS],
+    ) -> f64 {
+        let (key, is_major, _) = Self::best_key(mean_chroma);
+        let profile = if is_major { &MAJOR_PROFILE } else { &MINOR_PROFILE };
+
+        let coherent_frames = chroma_vectors
+            .iter()
+            .filter(|chroma| {
+                Self::profile_correlation(chroma, profile, key) >= DISCORD_CORRELATION_THRESHOLD
+            })
+            .count();
+
+        coherent_frames as f64 / chroma_vectors.len() as f64
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_tonality_sensor_creation() {
+        let sensor = TonalitySensor::new(None);
+        assert_eq!(sensor.name, "tonality_sensor");
+        assert!((sensor.threshold - DEFAULT_THRESHOLD).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_tonality_sensor_custom_threshold() {
+        let sensor = TonalitySensor::new(Some(0.8));
+        assert!((sensor.threshold - 0.8).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_best_key_identifies_c_major_profile() {
+        let (key, is_major, clarity) = TonalitySensor::best_key(&MAJOR_PROFILE);
+        assert_eq!(key, 0);
+        assert!(is_major);
+        assert!((clarity - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_best_key_identifies_rotated_major_profile() {
+        // Rotate the major profile by 7 semitones (G major)
+        let mut rotated = [0.0; CHROMA_BINS];
+        for i in 0..CHROMA_BINS {
+            rotated[(i + 7) % CHROMA_BINS] = MAJOR_PROFILE[i];
+        }
+        let (key, is_major, _) = TonalitySensor::best_key(&rotated);
+        assert_eq!(key, 7);
+        assert!(is_major);
+    }
+
+    #[test]
+    fn test_key_clarity_score_rewards_strong_correlation() {
+        let sensor = TonalitySensor::new(None);
+        assert!((sensor.compute_key_clarity_score(1.0) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_key_clarity_score_flags_weak_correlation() {
+        let sensor = TonalitySensor::new(None);
+        assert!(sensor.compute_key_clarity_score(0.05) < 0.5);
+    }
+
+    #[test]
+    fn test_key_coherence_score_is_high_for_consistent_key() {
+        let sensor = TonalitySensor::new(None);
+        let vectors = vec![MAJOR_PROFILE; 5];
+        let mean_chroma = TonalitySensor::mean_chroma(&vectors);
+        let score = sensor.compute_key_coherence_score(&vectors, &mean_chroma);
+        assert!((score - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_key_coherence_score_flags_drifting_key() {
+        let sensor = TonalitySensor::new(None);
+        let mut rotated = [0.0; CHROMA_BINS];
+        for i in 0..CHROMA_BINS {
+            rotated[(i + 6) % CHROMA_BINS] = MAJOR_PROFILE[i];
+        }
+        // Half the clip stays in the tonic key, half drifts to a distant key
+        let vectors = vec![
+            MAJOR_PROFILE,
+            MAJOR_PROFILE,
+            MAJOR_PROFILE,
+            rotated,
+            rotated,
+            rotated,
+        ];
+        let mean_chroma = TonalitySensor::mean_chroma(&vectors);
+        let score = sensor.compute_key_coherence_score(&vectors, &mean_chroma);
+        assert!(score < 1.0);
+    }
+}
@@ -8,8 +8,16 @@ use numpy::PyReadonlyArray1;
 use pyo3::prelude::*;
 
 use crate::sensors::result::SensorResult;
-use crate::utils::audio::{apply_hamming_window, calculate_rms, frame_audio, validate_audio_input};
-use crate::utils::fft::{compute_fft, magnitude_spectrum, spectral_centroid, spectral_bandwidth};
+use crate::utils::audio::{
+    apply_hamming_window, calculate_rms, downmix_interleaved, frame_audio, resample_to,
+    validate_audio_input, CANONICAL_SAMPLE_RATE,
+};
+use crate::utils::fft::{
+    compute_fft, magnitude_spectrum, spectral_bandwidth, spectral_centroid, spectral_flatness,
+    spectral_rolloff,
+};
+use crate::utils::lpc::{autocorrelation, formants_from_lpc, lpc_order, lpc_residual, levinson_durbin, residual_flatness};
+use crate::utils::pitch::{estimate_pitch, jitter_shimmer, DEFAULT_VOICING_THRESHOLD, MAX_VOICE_HZ, MIN_VOICE_HZ};
 
 /// Default threshold for vacuum sensor pass/fail decision
 const DEFAULT_THRESHOLD: f64 = 0.7;
@@ -20,6 +28,9 @@ const FRAME_SIZE: usize = 400;
 /// Hop size in samples (10ms at 16kHz)
 const HOP_SIZE: usize = 160;
 
+/// Fraction of total spectral energy used for the rolloff point
+const ROLLOFF_PERCENT: f64 = 0.85;
+
 /// Vacuum Sensor for Source-Filter Model analysis
 ///
 /// This sensor analyzes audio using principles of the source-filter model
@@ -71,8 +82,11 @@ impl VacuumSensor {
     /// Analyze audio data for source-filter model anomalies
     ///
     /// # Arguments
-    /// * `audio` - Audio samples as numpy array (f64)
+    /// * `audio` - Audio samples as numpy array (f64), interleaved across
+    ///   channels when `channels` > 1
     /// * `sample_rate` - Sample rate in Hz
+    /// * `channels` - Number of interleaved channels in `audio`, default 1
+    ///   (mono); multichannel input is averaged down to mono before analysis
     ///
     /// # Returns
     /// SensorResult with pass/fail decision and analysis details
@@ -80,88 +94,168 @@ impl VacuumSensor {
     /// # Security
     /// - Validates all input data
     /// - Handles edge cases gracefully
+    #[pyo3(signature = (audio, sample_rate, channels=None))]
     pub fn analyze(
         &self,
         audio: PyReadonlyArray1<'_, f64>,
         sample_rate: u32,
+        channels: Option<u32>,
     ) -> PyResult<SensorResult> {
         // Convert numpy array to slice with bounds checking
         let audio_slice = audio.as_slice()?;
+        let mono = downmix_interleaved(audio_slice, channels);
+        Ok(self.analyze_samples(&mono, sample_rate))
+    }
+
+    /// Load, resample, and analyze an audio file in one call
+    ///
+    /// # Arguments
+    /// * `path` - Path to an audio file decodable by `utils::io`
+    /// * `target_rate` - Sample rate to resample to before analysis, default 16000
+    ///
+    /// # Returns
+    /// SensorResult with pass/fail decision and analysis details
+    #[pyo3(signature = (path, target_rate=None))]
+    pub fn analyze_file(&self, path: String, target_rate: Option<u32>) -> PyResult<SensorResult> {
+        let rate = target_rate.unwrap_or(crate::utils::io::DEFAULT_TARGET_RATE);
+        match crate::utils::io::load_and_prepare(&path, rate) {
+            Ok(audio) => Ok(self.analyze_samples(&audio, rate)),
+            Err(e) => Ok(SensorResult::new(
+                self.name.clone(),
+                Some(false),
+                0.0,
+                self.threshold,
+                Some("io_error".to_string()),
+                Some(format!("Failed to load audio file: {}", e)),
+            )),
+        }
+    }
+
+    /// String representation for Python
+    fn __repr__(&self) -> String {
+        format!(
+            "VacuumSensor(name='{}', threshold={})",
+            self.name, self.threshold
+        )
+    }
+}
 
+impl VacuumSensor {
+    /// Analyze a raw sample slice, shared by `analyze` and `analyze_file`
+    fn analyze_samples(&self, audio_slice: &[f64], sample_rate: u32) -> SensorResult {
         // Validate input
         if let Err(e) = validate_audio_input(audio_slice, sample_rate) {
-            return Ok(SensorResult::new(
+            return SensorResult::new(
                 self.name.clone(),
                 Some(false),
                 0.0,
                 self.threshold,
                 Some("validation_error".to_string()),
                 Some(format!("Input validation failed: {}", e)),
-            ));
+            );
         }
 
+        // Resample to the canonical rate so the sensor's frame/hop
+        // constants apply exactly regardless of the input sample rate
+        let resampled = resample_to(audio_slice, sample_rate, CANONICAL_SAMPLE_RATE);
+
         // Perform analysis
-        let authenticity_score = self.compute_sfm_score(audio_slice, sample_rate);
+        let (authenticity_score, source_filter_detail, cutoff_detail, pitch_detail) =
+            self.compute_sfm_score(&resampled, CANONICAL_SAMPLE_RATE);
 
         // Determine pass/fail
         let passed = authenticity_score >= self.threshold;
 
         let detail = if passed {
             format!(
-                "Source-filter model analysis passed (score: {:.2})",
-                authenticity_score
+                "Source-filter model analysis passed (score: {:.2}, {}, {}, {})",
+                authenticity_score, source_filter_detail, cutoff_detail, pitch_detail
             )
         } else {
             format!(
-                "Potential synthetic audio detected (score: {:.2})",
-                authenticity_score
+                "Potential synthetic audio detected (score: {:.2}, {}, {}, {})",
+                authenticity_score, source_filter_detail, cutoff_detail, pitch_detail
             )
         };
 
-        Ok(SensorResult::new(
+        let reason = if !passed {
+            if cutoff_detail.is_anomalous {
+                Some("spectral_cutoff".to_string())
+            } else {
+                Some("sfm_anomaly".to_string())
+            }
+        } else {
+            None
+        };
+
+        let mut result = SensorResult::new(
             self.name.clone(),
             Some(passed),
             authenticity_score,
             self.threshold,
-            if !passed {
-                Some("sfm_anomaly".to_string())
-            } else {
-                None
-            },
+            reason,
             Some(detail),
-        ))
-    }
+        );
 
-    /// String representation for Python
-    fn __repr__(&self) -> String {
-        format!(
-            "VacuumSensor(name='{}', threshold={})",
-            self.name, self.threshold
-        )
-    }
-}
+        result.add_metadata(
+            "formant_tracks".to_string(),
+            source_filter_detail.formant_track_summary(),
+        );
+        result.add_metadata(
+            "residual_flatness".to_string(),
+            format!("{:.3}", source_filter_detail.mean_residual_flatness),
+        );
+        if let Some(cutoff_freq) = cutoff_detail.cutoff_freq {
+            result.add_metadata("spectral_cutoff_hz".to_string(), format!("{:.0}", cutoff_freq));
+        }
+        if let Some(mean_f0) = pitch_detail.mean_f0 {
+            result.add_metadata("mean_f0_hz".to_string(), format!("{:.1}", mean_f0));
+            result.add_metadata("jitter".to_string(), format!("{:.4}", pitch_detail.jitter));
+            result.add_metadata("shimmer".to_string(), format!("{:.4}", pitch_detail.shimmer));
+        }
 
-impl VacuumSensor {
+        result
+    }
     /// Compute source-filter model authenticity score
     ///
     /// Returns a score from 0.0 (likely synthetic) to 1.0 (likely authentic)
-    fn compute_sfm_score(&self, audio: &[f64], sample_rate: u32) -> f64 {
-        // Adjust frame parameters based on sample rate
-        let frame_size = (sample_rate as usize * FRAME_SIZE) / 16000;
-        let hop_size = (sample_rate as usize * HOP_SIZE) / 16000;
-
-        // Frame the audio
-        let frames = frame_audio(audio, frame_size, hop_size);
+    /// along with the source-filter decomposition details (formant tracks
+    /// and residual flatness), the spectral-cutoff details, and the
+    /// pitch-naturalness details used to reach it.
+    fn compute_sfm_score(
+        &self,
+        audio: &[f64],
+        sample_rate: u32,
+    ) -> (f64, SourceFilterDetail, CutoffDetail, PitchDetail) {
+        // Frame the audio (already resampled to `CANONICAL_SAMPLE_RATE`, so
+        // the frame/hop constants apply exactly)
+        let frames = frame_audio(audio, FRAME_SIZE, HOP_SIZE);
 
         if frames.is_empty() {
-            return 0.5; // Neutral score for insufficient data
+            return (
+                0.5,
+                SourceFilterDetail::default(),
+                CutoffDetail::default(),
+                PitchDetail::default(),
+            ); // Neutral score for insufficient data
         }
 
         // Pre-allocate with estimated capacity
         let mut spectral_features: Vec<SpectralFeatures> = Vec::with_capacity(frames.len());
+        let mut lpc_features: Vec<LpcFrameFeatures> = Vec::with_capacity(frames.len());
 
         // Pre-compute frequency bins once (same for all frames of same size)
-        let freq_resolution = sample_rate as f64 / frame_size as f64;
+        let freq_resolution = sample_rate as f64 / FRAME_SIZE as f64;
+        let lpc_order_value = lpc_order(sample_rate).min(FRAME_SIZE.saturating_sub(1)).max(2);
+
+        // Accumulated magnitude spectrum across all analyzed frames, used
+        // to estimate a sharp energy cutoff well below Nyquist (a common
+        // neural-vocoder/upsampling artifact)
+        let mut accumulated_magnitudes: Vec<f64> = Vec::new();
+
+        // Per-frame F0 estimates from voiced frames, used to measure
+        // cycle-to-cycle jitter/shimmer
+        let mut pitch_track: Vec<crate::utils::pitch::PitchEstimate> = Vec::new();
 
         for frame in &frames {
             // Apply window
@@ -185,27 +279,184 @@ impl VacuumSensor {
                 // Compute spectral features
                 let centroid = spectral_centroid(&magnitudes, &freqs);
                 let bandwidth = spectral_bandwidth(&magnitudes, &freqs, centroid);
+                let flatness = spectral_flatness(&magnitudes);
+                let rolloff_idx = spectral_rolloff(&magnitudes, ROLLOFF_PERCENT);
+                let rolloff_freq = freqs.get(rolloff_idx).copied().unwrap_or(0.0);
 
                 spectral_features.push(SpectralFeatures {
                     centroid,
                     bandwidth,
                     rms,
+                    flatness,
+                    rolloff_freq,
                 });
+
+                if accumulated_magnitudes.is_empty() {
+                    accumulated_magnitudes = magnitudes.clone();
+                } else {
+                    let n = accumulated_magnitudes.len().min(magnitudes.len());
+                    for i in 0..n {
+                        accumulated_magnitudes[i] += magnitudes[i];
+                    }
+                }
+            }
+
+            // Source-filter decomposition: separate the vocal-tract filter
+            // (formants) from the glottal source (prediction residual)
+            let autocorr = autocorrelation(&windowed, lpc_order_value);
+            if let Some(lpc_result) = levinson_durbin(&autocorr, lpc_order_value) {
+                let formants = formants_from_lpc(&lpc_result.coefficients, sample_rate, 3);
+                let residual = lpc_residual(&windowed, &lpc_result.coefficients);
+
+                lpc_features.push(LpcFrameFeatures {
+                    f1: formants.first().map(|f| f.frequency),
+                    f2: formants.get(1).map(|f| f.frequency),
+                    mean_formant_bandwidth: if formants.is_empty() {
+                        None
+                    } else {
+                        Some(formants.iter().map(|f| f.bandwidth).sum::<f64>() / formants.len() as f64)
+                    },
+                    residual_flatness: residual_flatness(&residual),
+                });
+            }
+
+            // Pitch-naturalness: track F0 across voiced frames to measure
+            // cycle-to-cycle jitter and shimmer
+            if let Some(pitch_estimate) = estimate_pitch(
+                &windowed,
+                sample_rate,
+                MIN_VOICE_HZ,
+                MAX_VOICE_HZ,
+                DEFAULT_VOICING_THRESHOLD,
+            ) {
+                pitch_track.push(pitch_estimate);
             }
         }
 
         if spectral_features.len() < 3 {
-            return 0.5; // Insufficient frames for analysis
+            return (
+                0.5,
+                SourceFilterDetail::default(),
+                CutoffDetail::default(),
+                PitchDetail::default(),
+            ); // Insufficient frames for analysis
         }
 
+        let cutoff_detail =
+            self.compute_cutoff_detail(&accumulated_magnitudes, freq_resolution, sample_rate);
+        let pitch_detail = self.compute_pitch_detail(&pitch_track);
+
         // Analyze feature patterns
-        self.analyze_patterns(&spectral_features)
+        let (score, source_filter_detail) = self.analyze_patterns(
+            &spectral_features,
+            &lpc_features,
+            &cutoff_detail,
+            &pitch_detail,
+            HOP_SIZE,
+            sample_rate,
+        );
+
+        (score, source_filter_detail, cutoff_detail, pitch_detail)
     }
 
-    /// Analyze spectral patterns for authenticity indicators
-    fn analyze_patterns(&self, features: &[SpectralFeatures]) -> f64 {
+    /// Compute the amplitude-modulation naturalness score
+    ///
+    /// Treats the per-frame RMS sequence as a low-rate signal sampled at
+    /// `sample_rate/hop_size` and takes its FFT to obtain a modulation
+    /// spectrum. Natural speech concentrates amplitude modulation in the
+    /// ~3-8 Hz syllabic range; a flat or anomalously peaked modulation
+    /// spectrum indicates synthetic rhythm artifacts that frame-local
+    /// variance cannot capture.
+    fn compute_modulation_score(
+        &self,
+        features: &[SpectralFeatures],
+        hop_size: usize,
+        sample_rate: u32,
+    ) -> f64 {
+        if features.len() < 8 || hop_size == 0 {
+            return 1.0;
+        }
+
+        let envelope: Vec<f64> = features.iter().map(|f| f.rms).collect();
+        let windowed = apply_hamming_window(&envelope);
+
+        let fft_result = match compute_fft(&windowed) {
+            Ok(result) => result,
+            Err(_) => return 1.0,
+        };
+        let magnitudes = magnitude_spectrum(&fft_result);
+
+        let modulation_rate = sample_rate as f64 / hop_size as f64;
+        let freq_resolution = modulation_rate / windowed.len() as f64;
+
+        let total_energy: f64 = magnitudes.iter().sum();
+        if total_energy < 1e-9 {
+            return 1.0;
+        }
+
+        let syllabic_energy: f64 = magnitudes
+            .iter()
+            .enumerate()
+            .filter(|(i, _)| {
+                let freq = *i as f64 * freq_resolution;
+                (3.0..=8.0).contains(&freq)
+            })
+            .map(|(_, &m)| m)
+            .sum();
+
+        let ratio = syllabic_energy / total_energy;
+
+        // Natural speech concentrates a meaningful but not dominant share
+        // of modulation energy in the syllabic band
+        if ratio < 0.15 {
+            (ratio / 0.15).clamp(0.0, 1.0)
+        } else if ratio > 0.8 {
+            ((1.0 - ratio) / 0.2).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
+    }
+
+    /// Compute the pitch-naturalness score from a voiced F0 track
+    ///
+    /// Natural phonation has small but nonzero jitter/shimmer; near-zero
+    /// values suggest an unnaturally steady synthetic pitch, while
+    /// implausibly large values suggest noise or a tracking failure.
+    fn compute_pitch_detail(&self, track: &[crate::utils::pitch::PitchEstimate]) -> PitchDetail {
+        if track.len() < 4 {
+            return PitchDetail {
+                score: 1.0,
+                ..PitchDetail::default()
+            };
+        }
+
+        let (jitter, shimmer) = jitter_shimmer(track);
+        let mean_f0 = track.iter().map(|p| p.frequency).sum::<f64>() / track.len() as f64;
+
+        // Natural speech jitter is roughly 0.5-2%, shimmer roughly 3-10%
+        let jitter_score = banded_naturalness_score(jitter, 0.003, 0.03);
+        let shimmer_score = banded_naturalness_score(shimmer, 0.02, 0.15);
+
+        PitchDetail {
+            score: (jitter_score + shimmer_score) / 2.0,
+            mean_f0: Some(mean_f0),
+            jitter,
+            shimmer,
+        }
+    }
+
+    /// Analyze spectral and source-filter patterns for authenticity indicators
+    fn analyze_patterns(
+        &self,
+        features: &[SpectralFeatures],
+        lpc_features: &[LpcFrameFeatures],
+        cutoff_detail: &CutoffDetail,
+        pitch_detail: &PitchDetail,
+        hop_size: usize,
+        sample_rate: u32,
+    ) -> (f64, SourceFilterDetail) {
         if features.is_empty() {
-            return 0.5;
+            return (0.5, SourceFilterDetail::default());
         }
 
         // 1. Spectral centroid stability (authentic speech has natural variation)
@@ -232,14 +483,199 @@ impl VacuumSensor {
         // 4. Smoothness score (frame-to-frame transitions)
         let smoothness_score = self.compute_smoothness_score(features);
 
-        // Combined score with weights
-        let combined = 0.25 * centroid_score
-            + 0.25 * bandwidth_score
-            + 0.25 * energy_score
-            + 0.25 * smoothness_score;
+        // 4b. Modulation-spectrum naturalness (rhythm-level artifacts that
+        // frame-local smoothness cannot capture)
+        let modulation_score = self.compute_modulation_score(features, hop_size, sample_rate);
+
+        // 5. Spectral flatness stability (vocoded audio often shows
+        // abnormally high, near-constant flatness in the high bands)
+        let flatness_score = self.compute_stability_score(
+            &features.iter().map(|f| f.flatness).collect::<Vec<_>>(),
+            0.01,
+            0.3,
+        );
+
+        // 6. Rolloff stability (a rolloff that barely moves frame-to-frame
+        // suggests an artificially fixed spectral envelope)
+        let rolloff_score = self.compute_stability_score(
+            &features.iter().map(|f| f.rolloff_freq).collect::<Vec<_>>(),
+            100.0,
+            1500.0,
+        );
+
+        // 7. Formant bandwidth score (synthesizers tend toward unnaturally
+        // narrow, over-stable formants)
+        let (formant_score, mut detail) = self.compute_formant_score(lpc_features);
+
+        // 8. Residual flatness score (over-whitened or over-structured
+        // residuals both indicate a non-glottal excitation source)
+        let residual_score = self.compute_residual_score(lpc_features);
+        detail.mean_residual_flatness = if lpc_features.is_empty() {
+            0.0
+        } else {
+            lpc_features.iter().map(|f| f.residual_flatness).sum::<f64>() / lpc_features.len() as f64
+        };
+
+        // 9. Spectral cutoff score (a sharp energy cutoff well below
+        // Nyquist is a common neural-vocoder/upsampling artifact)
+        let cutoff_score = cutoff_detail.score;
+
+        // 10. Pitch-naturalness score (jitter/shimmer of the F0 track)
+        let pitch_score = pitch_detail.score;
+
+        // Combined score with equal weighting across all eleven terms
+        let weight = 1.0 / 11.0;
+        let combined = weight
+            * (centroid_score
+                + bandwidth_score
+                + energy_score
+                + smoothness_score
+                + flatness_score
+                + rolloff_score
+                + formant_score
+                + residual_score
+                + cutoff_score
+                + pitch_score
+                + modulation_score);
 
         // Clamp to valid range
-        combined.clamp(0.0, 1.0)
+        (combined.clamp(0.0, 1.0), detail)
+    }
+
+    /// Estimate a sharp spectral energy cutoff well below Nyquist
+    ///
+    /// Accumulates energy from the top frequency bin downward and finds the
+    /// highest frequency where the cumulative-from-top energy first exceeds
+    /// `1 - percentile` of the total. A cutoff that is both sharp (a steep
+    /// energy drop within a few bins) and far below the expected effective
+    /// bandwidth for `sample_rate` indicates synthetic upsampling/vocoding.
+    fn compute_cutoff_detail(
+        &self,
+        accumulated_magnitudes: &[f64],
+        freq_resolution: f64,
+        sample_rate: u32,
+    ) -> CutoffDetail {
+        const PERCENTILE: f64 = 0.97;
+        let neutral = CutoffDetail {
+            score: 1.0,
+            cutoff_freq: None,
+            is_anomalous: false,
+        };
+
+        if accumulated_magnitudes.len() < 8 {
+            return neutral;
+        }
+
+        let total_energy: f64 = accumulated_magnitudes.iter().sum();
+        if total_energy < 1e-9 {
+            return neutral;
+        }
+
+        let target = total_energy * (1.0 - PERCENTILE);
+
+        let mut cumulative_from_top = 0.0;
+        let mut cutoff_index = accumulated_magnitudes.len() - 1;
+        for i in (0..accumulated_magnitudes.len()).rev() {
+            cumulative_from_top += accumulated_magnitudes[i];
+            if cumulative_from_top > target {
+                cutoff_index = i;
+                break;
+            }
+        }
+
+        let cutoff_freq = cutoff_index as f64 * freq_resolution;
+        let nyquist = sample_rate as f64 / 2.0;
+        let expected_bandwidth = nyquist * 0.9;
+
+        // Sharpness: how much the energy drops across a short window just
+        // above the detected cutoff, relative to the window just below it
+        let window = (accumulated_magnitudes.len() / 32).max(2);
+        let below_start = cutoff_index.saturating_sub(window);
+        let above_end = (cutoff_index + window).min(accumulated_magnitudes.len());
+
+        let energy_below: f64 = accumulated_magnitudes[below_start..cutoff_index].iter().sum();
+        let energy_above: f64 = accumulated_magnitudes[cutoff_index..above_end].iter().sum();
+        let is_sharp = energy_below > 1e-9 && (energy_above / energy_below) < 0.1;
+
+        let is_far_below_nyquist = cutoff_freq < expected_bandwidth;
+        let is_anomalous = is_sharp && is_far_below_nyquist;
+
+        let score = if is_anomalous {
+            (cutoff_freq / expected_bandwidth).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        CutoffDetail {
+            score,
+            cutoff_freq: Some(cutoff_freq),
+            is_anomalous,
+        }
+    }
+
+    /// Compute the formant-bandwidth naturalness score
+    ///
+    /// Synthesizers tend to produce unnaturally narrow and unnaturally
+    /// stable formants; genuine vocal-tract resonances have moderate
+    /// bandwidths (tens to low hundreds of Hz) that vary across an
+    /// utterance.
+    fn compute_formant_score(&self, lpc_features: &[LpcFrameFeatures]) -> (f64, SourceFilterDetail) {
+        let bandwidths: Vec<f64> = lpc_features
+            .iter()
+            .filter_map(|f| f.mean_formant_bandwidth)
+            .collect();
+
+        if bandwidths.is_empty() {
+            return (0.5, SourceFilterDetail::default());
+        }
+
+        let mean_bandwidth = bandwidths.iter().sum::<f64>() / bandwidths.len() as f64;
+
+        // Natural formant bandwidths are typically 50-400 Hz; narrower
+        // bandwidths suggest an overly precise synthetic resonance
+        let score = if mean_bandwidth < 50.0 {
+            (mean_bandwidth / 50.0).clamp(0.0, 1.0)
+        } else if mean_bandwidth > 600.0 {
+            (600.0 / mean_bandwidth).clamp(0.0, 1.0)
+        } else {
+            1.0
+        };
+
+        let mean_f1 = average_option(lpc_features.iter().map(|f| f.f1));
+        let mean_f2 = average_option(lpc_features.iter().map(|f| f.f2));
+
+        (
+            score,
+            SourceFilterDetail {
+                mean_f1,
+                mean_f2,
+                mean_formant_bandwidth: mean_bandwidth,
+                mean_residual_flatness: 0.0,
+            },
+        )
+    }
+
+    /// Compute the LPC residual naturalness score
+    ///
+    /// Genuine glottal excitation is quasi-periodic/impulsive, landing in
+    /// the middle of the flatness range; a residual that is nearly pure
+    /// noise (over-whitened) or nearly constant (over-structured) both
+    /// indicate a non-glottal source.
+    fn compute_residual_score(&self, lpc_features: &[LpcFrameFeatures]) -> f64 {
+        if lpc_features.is_empty() {
+            return 0.5;
+        }
+
+        let mean_flatness = lpc_features.iter().map(|f| f.residual_flatness).sum::<f64>()
+            / lpc_features.len() as f64;
+
+        if mean_flatness < 0.15 {
+            (mean_flatness / 0.15).clamp(0.0, 1.0)
+        } else if mean_flatness > 0.75 {
+            ((1.0 - mean_flatness) / 0.25).clamp(0.0, 1.0)
+        } else {
+            1.0
+        }
     }
 
     /// Compute stability score based on variance within expected range
@@ -308,6 +744,121 @@ struct SpectralFeatures {
     centroid: f64,
     bandwidth: f64,
     rms: f64,
+    flatness: f64,
+    rolloff_freq: f64,
+}
+
+/// Per-frame source-filter decomposition features
+struct LpcFrameFeatures {
+    /// First formant frequency (Hz), if the LPC solve succeeded
+    f1: Option<f64>,
+    /// Second formant frequency (Hz), if the LPC solve succeeded
+    f2: Option<f64>,
+    /// Mean bandwidth across the extracted formants (Hz)
+    mean_formant_bandwidth: Option<f64>,
+    /// Flatness of the LPC prediction residual, in `[0, 1]`
+    residual_flatness: f64,
+}
+
+/// Summary of the source-filter decomposition across an utterance, surfaced
+/// in the `SensorResult` detail/metadata
+#[derive(Debug, Clone, Default)]
+struct SourceFilterDetail {
+    mean_f1: Option<f64>,
+    mean_f2: Option<f64>,
+    mean_formant_bandwidth: f64,
+    mean_residual_flatness: f64,
+}
+
+impl SourceFilterDetail {
+    /// Human-readable summary of the tracked formants
+    fn formant_track_summary(&self) -> String {
+        match (self.mean_f1, self.mean_f2) {
+            (Some(f1), Some(f2)) => format!("F1~{:.0}Hz F2~{:.0}Hz (bw~{:.0}Hz)", f1, f2, self.mean_formant_bandwidth),
+            (Some(f1), None) => format!("F1~{:.0}Hz (bw~{:.0}Hz)", f1, self.mean_formant_bandwidth),
+            _ => "formants unresolved".to_string(),
+        }
+    }
+}
+
+impl std::fmt::Display for SourceFilterDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}, residual flatness {:.2}",
+            self.formant_track_summary(),
+            self.mean_residual_flatness
+        )
+    }
+}
+
+/// Summary of the spectral-cutoff estimate, surfaced in the `SensorResult`
+/// detail/metadata
+#[derive(Debug, Clone, Default)]
+struct CutoffDetail {
+    score: f64,
+    cutoff_freq: Option<f64>,
+    is_anomalous: bool,
+}
+
+impl std::fmt::Display for CutoffDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.cutoff_freq {
+            Some(freq) if self.is_anomalous => {
+                write!(f, "sharp spectral cutoff at {:.0}Hz", freq)
+            }
+            Some(freq) => write!(f, "spectral cutoff ~{:.0}Hz", freq),
+            None => write!(f, "spectral cutoff unresolved"),
+        }
+    }
+}
+
+/// Summary of the pitch-naturalness estimate, surfaced in the
+/// `SensorResult` detail/metadata
+#[derive(Debug, Clone, Default)]
+struct PitchDetail {
+    score: f64,
+    mean_f0: Option<f64>,
+    jitter: f64,
+    shimmer: f64,
+}
+
+impl std::fmt::Display for PitchDetail {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.mean_f0 {
+            Some(f0) => write!(
+                f,
+                "F0~{:.0}Hz jitter {:.2}% shimmer {:.2}%",
+                f0,
+                self.jitter * 100.0,
+                self.shimmer * 100.0
+            ),
+            None => write!(f, "pitch unresolved"),
+        }
+    }
+}
+
+/// Score a measured value against a natural `[min, max]` band: values near
+/// zero (too perfect) or far above `max` (too erratic) both score low,
+/// mirroring the banded scoring used elsewhere in this sensor
+fn banded_naturalness_score(value: f64, min: f64, max: f64) -> f64 {
+    if value < min {
+        (value / min).clamp(0.0, 1.0)
+    } else if value > max {
+        (max / value).clamp(0.0, 1.0)
+    } else {
+        1.0
+    }
+}
+
+/// Average an iterator of optional values, ignoring `None` entries
+fn average_option(values: impl Iterator<Item = Option<f64>>) -> Option<f64> {
+    let (sum, count) = values.flatten().fold((0.0, 0usize), |(sum, count), v| (sum + v, count + 1));
+    if count == 0 {
+        None
+    } else {
+        Some(sum / count as f64)
+    }
 }
 
 #[cfg(test)]
@@ -361,16 +912,22 @@ mod tests {
                 centroid: 1000.0,
                 bandwidth: 500.0,
                 rms: 0.1,
+                flatness: 0.1,
+                rolloff_freq: 3000.0,
             },
             SpectralFeatures {
                 centroid: 1010.0,
                 bandwidth: 510.0,
                 rms: 0.11,
+                flatness: 0.11,
+                rolloff_freq: 3050.0,
             },
             SpectralFeatures {
                 centroid: 1020.0,
                 bandwidth: 520.0,
                 rms: 0.12,
+                flatness: 0.12,
+                rolloff_freq: 3100.0,
             },
         ];
         let score = sensor.compute_smoothness_score(&features);
@@ -187,6 +187,196 @@ pub fn zero_crossing_rate(audio: &[f64]) -> f64 {
     crossings as f64 / (audio.len() - 1) as f64
 }
 
+/// Canonical internal sample rate sensors analyze at after `resample_to`
+pub const CANONICAL_SAMPLE_RATE: u32 = 16000;
+
+/// Fixed-point scale for `resample_to`'s position accumulator
+const RESAMPLE_FIXED_POINT_SCALE: u64 = 1 << 32;
+
+/// Resample audio from `src_rate` to `dst_rate` via linear interpolation
+///
+/// Walks an integer-plus-fraction position accumulator (`ipos`, `frac`)
+/// through the source in fixed-point units of the `src_rate / dst_rate`
+/// step, advancing it by that step each output sample and linearly
+/// interpolating `out = src[ipos]*(1-f) + src[ipos+1]*f` where
+/// `f = frac / SCALE`. Stops before reading past the last source sample.
+///
+/// This is a cheap, exact-ratio front-end for the sensors' fixed frame/hop
+/// constants -- unlike `utils::io::resample`'s band-limited sinc kernel,
+/// it favors speed over anti-aliasing and is meant for the raw-array
+/// `analyze` path rather than file decoding.
+///
+/// # Arguments
+/// * `audio` - Source samples
+/// * `src_rate` - Source sample rate in Hz
+/// * `dst_rate` - Destination sample rate in Hz
+pub fn resample_to(audio: &[f64], src_rate: u32, dst_rate: u32) -> Vec<f64> {
+    if audio.is_empty() || src_rate == 0 || dst_rate == 0 || src_rate == dst_rate {
+        return audio.to_vec();
+    }
+
+    let step = (src_rate as u64 * RESAMPLE_FIXED_POINT_SCALE) / dst_rate as u64;
+    let estimated_len = (audio.len() as u64 * dst_rate as u64 / src_rate as u64) as usize;
+
+    let mut output = Vec::with_capacity(estimated_len);
+    let mut pos: u64 = 0;
+
+    loop {
+        let ipos = (pos / RESAMPLE_FIXED_POINT_SCALE) as usize;
+        if ipos + 1 >= audio.len() {
+            break;
+        }
+
+        let frac = pos % RESAMPLE_FIXED_POINT_SCALE;
+        let f = frac as f64 / RESAMPLE_FIXED_POINT_SCALE as f64;
+
+        output.push(audio[ipos] * (1.0 - f) + audio[ipos + 1] * f);
+        pos += step;
+    }
+
+    output
+}
+
+/// Minimum human voice fundamental frequency considered (Hz)
+pub const MIN_F0_HZ: f64 = 80.0;
+
+/// Maximum human voice fundamental frequency considered (Hz)
+pub const MAX_F0_HZ: f64 = 400.0;
+
+/// Fraction of `r(0)` the peak autocorrelation must clear to be voiced
+pub const F0_VOICING_CONFIDENCE: f64 = 0.3;
+
+/// Estimate the fundamental frequency of a single frame via autocorrelation
+///
+/// Mean-subtracts the frame, computes `r(tau) = sum_i x[i] * x[i+tau]` over
+/// lags covering the human pitch range (mapped as `sample_rate / tau`, so
+/// 80-400 Hz at 16 kHz scans lags ~40-200), and picks the lag of the
+/// maximum correlation past the zero-lag peak. Returns `None` if the frame
+/// is unvoiced -- the peak doesn't clear `F0_VOICING_CONFIDENCE * r(0)`.
+///
+/// # Arguments
+/// * `frame` - A single (unwindowed or windowed) frame of audio samples
+/// * `sample_rate` - Sample rate in Hz
+pub fn find_fundamental_frequency(frame: &[f64], sample_rate: u32) -> Option<f64> {
+    let n = frame.len();
+    if n < 4 {
+        return None;
+    }
+
+    let sr = sample_rate as f64;
+    let min_lag = (sr / MAX_F0_HZ).floor().max(1.0) as usize;
+    let max_lag = (sr / MIN_F0_HZ).ceil() as usize;
+
+    if max_lag >= n || min_lag >= max_lag {
+        return None;
+    }
+
+    let mean = frame.iter().sum::<f64>() / n as f64;
+    let centered: Vec<f64> = frame.iter().map(|&x| x - mean).collect();
+
+    let r0: f64 = centered.iter().map(|&x| x * x).sum();
+    if r0.abs() < 1e-12 {
+        return None;
+    }
+
+    let mut best_lag = 0;
+    let mut best_value = f64::MIN;
+
+    for lag in min_lag..=max_lag {
+        let mut sum = 0.0;
+        for i in 0..n - lag {
+            sum += centered[i] * centered[i + lag];
+        }
+        if sum > best_value {
+            best_value = sum;
+            best_lag = lag;
+        }
+    }
+
+    if best_lag == 0 || best_value < F0_VOICING_CONFIDENCE * r0 {
+        return None;
+    }
+
+    Some(sr / best_lag as f64)
+}
+
+/// Channel downmix strategy for `apply_channel_op`
+///
+/// Lets the raw-array `analyze` entry points accept interleaved
+/// multichannel input without every sensor reimplementing its own
+/// downmix math -- `apply_channel_op` always reduces to the mono
+/// `Vec<f64>` the existing per-sensor pipeline consumes.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ChannelOp {
+    /// Input is already single-channel; returned unchanged
+    Passthrough,
+    /// Weighted sum of N channels, one coefficient per channel (e.g. an
+    /// equal-weight stereo average is `[0.5, 0.5]`)
+    Remix(Vec<f32>),
+    /// Input has `usize` identical duplicated channels (e.g. a mono
+    /// source copied to fill a stereo container); take the first
+    /// channel directly instead of redundantly averaging
+    DupMono(usize),
+}
+
+/// Reduce interleaved multichannel audio to mono per a `ChannelOp`
+///
+/// # Arguments
+/// * `samples` - Interleaved audio samples (frame-major: `ch0, ch1, ch0, ch1, ...`)
+/// * `op` - Downmix strategy to apply
+///
+/// # Returns
+/// Mono samples, one per input frame
+pub fn apply_channel_op(samples: &[f64], op: &ChannelOp) -> Vec<f64> {
+    match op {
+        ChannelOp::Passthrough => samples.to_vec(),
+        ChannelOp::Remix(coeffs) => {
+            let channels = coeffs.len();
+            if channels == 0 {
+                return Vec::new();
+            }
+            samples
+                .chunks(channels)
+                .filter(|frame| frame.len() == channels)
+                .map(|frame| {
+                    frame
+                        .iter()
+                        .zip(coeffs.iter())
+                        .map(|(&sample, &coeff)| sample * coeff as f64)
+                        .sum()
+                })
+                .collect()
+        }
+        ChannelOp::DupMono(channels) => {
+            if *channels <= 1 {
+                return samples.to_vec();
+            }
+            samples
+                .chunks(*channels)
+                .filter(|frame| frame.len() == *channels)
+                .map(|frame| frame[0])
+                .collect()
+        }
+    }
+}
+
+/// Downmix interleaved audio to mono for the raw-array `analyze` entry points
+///
+/// `channels` is the caller-declared channel count for `samples`
+/// (`None`/`Some(0)`/`Some(1)` all mean mono, left untouched). Multichannel
+/// input is reduced with an equal-weight `ChannelOp::Remix` average, matching
+/// `utils::io::to_mono`'s behavior for the file-decode path.
+///
+/// # Arguments
+/// * `samples` - Interleaved audio samples
+/// * `channels` - Declared channel count, default 1 (mono)
+pub fn downmix_interleaved(samples: &[f64], channels: Option<u32>) -> Vec<f64> {
+    match channels.unwrap_or(1).max(1) as usize {
+        1 => samples.to_vec(),
+        n => apply_channel_op(samples, &ChannelOp::Remix(vec![1.0 / n as f32; n])),
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -277,4 +467,100 @@ mod tests {
         assert!(windowed[0] < 0.1);
         assert!(windowed[4] > 0.9);
     }
+
+    #[test]
+    fn test_find_fundamental_frequency_on_sine() {
+        let sample_rate = 16000u32;
+        let freq = 150.0;
+        let frame: Vec<f64> = (0..1024)
+            .map(|i| (2.0 * std::f64::consts::PI * freq * i as f64 / sample_rate as f64).sin())
+            .collect();
+
+        let f0 = find_fundamental_frequency(&frame, sample_rate)
+            .expect("should detect pitch on a clean sine");
+        assert!((f0 - freq).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_find_fundamental_frequency_silent_frame() {
+        let frame = vec![0.0; 1024];
+        assert!(find_fundamental_frequency(&frame, 16000).is_none());
+    }
+
+    #[test]
+    fn test_find_fundamental_frequency_too_short() {
+        let frame = vec![0.1, 0.2, 0.3];
+        assert!(find_fundamental_frequency(&frame, 16000).is_none());
+    }
+
+    #[test]
+    fn test_resample_to_identity_when_rates_match() {
+        let audio = vec![0.1, 0.2, 0.3, 0.4];
+        assert_eq!(resample_to(&audio, 16000, 16000), audio);
+    }
+
+    #[test]
+    fn test_resample_to_upsample_preserves_length_ratio() {
+        let n = 256;
+        let audio: Vec<f64> = (0..n)
+            .map(|i| (2.0 * std::f64::consts::PI * 5.0 * i as f64 / n as f64).sin())
+            .collect();
+        let resampled = resample_to(&audio, 8000, 16000);
+        // The accumulator stops a couple of samples short of the exact
+        // ratio since it won't read past the last source sample
+        assert!((resampled.len() as i64 - (n as i64 * 2)).abs() <= 2);
+    }
+
+    #[test]
+    fn test_resample_to_downsample_takes_every_other_sample() {
+        let audio = vec![0.0, 1.0, 2.0, 3.0];
+        let resampled = resample_to(&audio, 16000, 8000);
+        assert!((resampled[0] - 0.0).abs() < 1e-9);
+        assert!((resampled[1] - 2.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_apply_channel_op_passthrough() {
+        let mono = vec![0.1, 0.2, 0.3];
+        assert_eq!(apply_channel_op(&mono, &ChannelOp::Passthrough), mono);
+    }
+
+    #[test]
+    fn test_apply_channel_op_remix_averages_stereo() {
+        let stereo = vec![1.0, -1.0, 0.5, 0.5];
+        let mono = apply_channel_op(&stereo, &ChannelOp::Remix(vec![0.5, 0.5]));
+        assert_eq!(mono, vec![0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_apply_channel_op_remix_applies_unequal_coefficients() {
+        let stereo = vec![1.0, 0.0, 1.0, 0.0];
+        let mono = apply_channel_op(&stereo, &ChannelOp::Remix(vec![1.0, 0.0]));
+        assert_eq!(mono, vec![1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_apply_channel_op_dup_mono_takes_first_channel() {
+        let duplicated = vec![0.3, 0.3, -0.4, -0.4];
+        let mono = apply_channel_op(&duplicated, &ChannelOp::DupMono(2));
+        assert_eq!(mono, vec![0.3, -0.4]);
+    }
+
+    #[test]
+    fn test_apply_channel_op_dup_mono_single_channel_is_passthrough() {
+        let mono = vec![0.1, 0.2, 0.3];
+        assert_eq!(apply_channel_op(&mono, &ChannelOp::DupMono(1)), mono);
+    }
+
+    #[test]
+    fn test_downmix_interleaved_defaults_to_mono_passthrough() {
+        let mono = vec![0.1, 0.2, 0.3];
+        assert_eq!(downmix_interleaved(&mono, None), mono);
+    }
+
+    #[test]
+    fn test_downmix_interleaved_averages_stereo() {
+        let stereo = vec![1.0, -1.0, 0.5, 0.5];
+        assert_eq!(downmix_interleaved(&stereo, Some(2)), vec![0.0, 0.5]);
+    }
 }
@@ -0,0 +1,222 @@
+//! Audio file I/O utilities
+//!
+//! Decodes audio files to `f64` mono, downmixes multichannel input,
+//! resamples to a canonical rate via band-limited sinc interpolation, and
+//! normalizes level -- so sensors can expose a `analyze_file(path)` entry
+//! point without every caller replicating decode/resample logic in Python.
+
+#![allow(dead_code)] // Some utilities reserved for future use
+
+use crate::utils::audio::normalize_audio;
+use crate::utils::errors::{SensorError, SensorResultType};
+
+/// Canonical sample rate sensors are tuned for
+pub const DEFAULT_TARGET_RATE: u32 = 16000;
+
+/// Half-width (in source samples) of the windowed-sinc resampling kernel
+const SINC_HALF_WIDTH: usize = 16;
+
+/// Decode an audio file to interleaved `f64` samples
+///
+/// # Arguments
+/// * `path` - Path to a WAV file (PCM or IEEE float)
+///
+/// # Returns
+/// `(interleaved_samples, sample_rate, channels)`
+///
+/// # Security
+/// - Propagates decode failures as `SensorError` rather than panicking
+pub fn load_audio(path: &str) -> SensorResultType<(Vec<f64>, u32, usize)> {
+    let mut reader = hound::WavReader::open(path)
+        .map_err(|e| SensorError::invalid_input(format!("Failed to open '{}': {}", path, e)))?;
+
+    let spec = reader.spec();
+    let channels = spec.channels as usize;
+    if channels == 0 {
+        return Err(SensorError::invalid_input("Audio file has zero channels"));
+    }
+
+    let samples: Result<Vec<f64>, _> = match spec.sample_format {
+        hound::SampleFormat::Float => reader.samples::<f32>().map(|s| s.map(|v| v as f64)).collect(),
+        hound::SampleFormat::Int => {
+            let max_value = (1i64 << (spec.bits_per_sample - 1)) as f64;
+            reader
+                .samples::<i32>()
+                .map(|s| s.map(|v| v as f64 / max_value))
+                .collect()
+        }
+    };
+
+    let samples = samples
+        .map_err(|e| SensorError::invalid_input(format!("Failed to decode '{}': {}", path, e)))?;
+
+    Ok((samples, spec.sample_rate, channels))
+}
+
+/// Write mono `f64` samples to a 16-bit PCM WAV file
+pub fn save_audio(path: &str, audio: &[f64], sample_rate: u32) -> SensorResultType<()> {
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+
+    let mut writer = hound::WavWriter::create(path, spec)
+        .map_err(|e| SensorError::InternalError(format!("Failed to create '{}': {}", path, e)))?;
+
+    for &sample in audio {
+        let clamped = sample.clamp(-1.0, 1.0);
+        let quantized = (clamped * i16::MAX as f64).round() as i16;
+        writer
+            .write_sample(quantized)
+            .map_err(|e| SensorError::InternalError(format!("Failed to write sample: {}", e)))?;
+    }
+
+    writer
+        .finalize()
+        .map_err(|e| SensorError::InternalError(format!("Failed to finalize '{}': {}", path, e)))
+}
+
+/// Downmix interleaved multichannel audio to mono by averaging channels
+///
+/// # Arguments
+/// * `samples` - Interleaved audio samples
+/// * `channels` - Number of interleaved channels
+pub fn to_mono(samples: &[f64], channels: usize) -> Vec<f64> {
+    if channels <= 1 {
+        return samples.to_vec();
+    }
+
+    samples
+        .chunks(channels)
+        .map(|frame| frame.iter().sum::<f64>() / frame.len() as f64)
+        .collect()
+}
+
+/// Peak-normalize audio to `[-1.0, 1.0]`
+///
+/// Thin wrapper around `utils::audio::normalize_audio` kept here so
+/// callers of the I/O pipeline don't need to reach into a second module.
+pub fn peak_normalize(audio: &[f64]) -> Vec<f64> {
+    normalize_audio(audio)
+}
+
+/// RMS-normalize audio to a target RMS level
+pub fn rms_normalize(audio: &[f64], target_rms: f64) -> Vec<f64> {
+    if audio.is_empty() {
+        return Vec::new();
+    }
+
+    let rms = (audio.iter().map(|&x| x * x).sum::<f64>() / audio.len() as f64).sqrt();
+    if rms < 1e-9 {
+        return audio.to_vec();
+    }
+
+    let gain = target_rms / rms;
+    audio.iter().map(|&x| x * gain).collect()
+}
+
+/// Resample audio from `src_rate` to `dst_rate` using band-limited sinc interpolation
+///
+/// Evaluates a windowed-sinc kernel (Lanczos window, `SINC_HALF_WIDTH`
+/// source samples either side) at each destination sample's fractional
+/// source position. This band-limits the signal before decimation and
+/// interpolates smoothly when upsampling.
+pub fn resample(audio: &[f64], src_rate: u32, dst_rate: u32) -> Vec<f64> {
+    if audio.is_empty() || src_rate == 0 || dst_rate == 0 || src_rate == dst_rate {
+        return audio.to_vec();
+    }
+
+    let ratio = dst_rate as f64 / src_rate as f64;
+    let out_len = ((audio.len() as f64) * ratio).round().max(1.0) as usize;
+
+    // When downsampling, widen the kernel proportionally to avoid aliasing
+    let scale = (ratio).min(1.0);
+    let kernel_half_width = (SINC_HALF_WIDTH as f64 / scale).ceil() as isize;
+
+    let mut output = Vec::with_capacity(out_len);
+    for n in 0..out_len {
+        let src_pos = n as f64 / ratio;
+        let center = src_pos.floor() as isize;
+
+        let mut acc = 0.0;
+        for k in (center - kernel_half_width)..=(center + kernel_half_width) {
+            if k < 0 || k as usize >= audio.len() {
+                continue;
+            }
+
+            let x = (src_pos - k as f64) * scale;
+            acc += audio[k as usize] * lanczos_sinc(x, SINC_HALF_WIDTH as f64);
+        }
+
+        output.push(acc);
+    }
+
+    output
+}
+
+/// Lanczos-windowed sinc kernel evaluated at `x`, truncated to `[-a, a]`
+fn lanczos_sinc(x: f64, a: f64) -> f64 {
+    if x.abs() < 1e-9 {
+        return 1.0;
+    }
+    if x.abs() >= a {
+        return 0.0;
+    }
+
+    let pi_x = std::f64::consts::PI * x;
+    a * (pi_x.sin() * (pi_x / a).sin()) / (pi_x * pi_x)
+}
+
+/// Load, downmix, resample, and peak-normalize an audio file in one call
+///
+/// This is the pipeline backing each sensor's `analyze_file` method.
+pub fn load_and_prepare(path: &str, target_rate: u32) -> SensorResultType<Vec<f64>> {
+    let (samples, src_rate, channels) = load_audio(path)?;
+    let mono = to_mono(&samples, channels);
+    let resampled = resample(&mono, src_rate, target_rate);
+    Ok(peak_normalize(&resampled))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_mono_averages_channels() {
+        let stereo = vec![1.0, -1.0, 0.5, 0.5];
+        let mono = to_mono(&stereo, 2);
+        assert_eq!(mono, vec![0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_to_mono_passthrough_when_mono() {
+        let mono = vec![0.1, 0.2, 0.3];
+        assert_eq!(to_mono(&mono, 1), mono);
+    }
+
+    #[test]
+    fn test_rms_normalize_reaches_target() {
+        let audio = vec![0.1, -0.1, 0.1, -0.1];
+        let normalized = rms_normalize(&audio, 0.5);
+        let rms = (normalized.iter().map(|&x| x * x).sum::<f64>() / normalized.len() as f64).sqrt();
+        assert!((rms - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_resample_identity_when_rates_match() {
+        let audio = vec![0.1, 0.2, 0.3, 0.4];
+        assert_eq!(resample(&audio, 16000, 16000), audio);
+    }
+
+    #[test]
+    fn test_resample_upsample_preserves_length_ratio() {
+        let n = 256;
+        let audio: Vec<f64> = (0..n)
+            .map(|i| (2.0 * std::f64::consts::PI * 5.0 * i as f64 / n as f64).sin())
+            .collect();
+        let resampled = resample(&audio, 8000, 16000);
+        assert_eq!(resampled.len(), n * 2);
+    }
+}
@@ -4,7 +4,15 @@
 //! - `errors`: Error types and handling
 //! - `audio`: Audio processing utilities
 //! - `fft`: FFT operations with bounds checking
+//! - `lpc`: Linear predictive coding (formants, residual)
+//! - `pitch`: Autocorrelation-based F0 tracking (jitter/shimmer)
+//! - `io`: Audio file decode, resample, and normalize
+//! - `preprocess`: Validated mono/resample/DC/normalize front-end for raw arrays
 
 pub mod audio;
 pub mod errors;
 pub mod fft;
+pub mod io;
+pub mod lpc;
+pub mod pitch;
+pub mod preprocess;
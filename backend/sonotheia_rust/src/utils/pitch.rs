@@ -0,0 +1,253 @@
+//! Pitch tracking utilities
+//!
+//! Autocorrelation-based fundamental frequency (F0) estimation, plus the
+//! cycle-to-cycle jitter/shimmer measures used to distinguish genuine
+//! phonation (small but nonzero period/amplitude perturbation) from
+//! synthesizers that reproduce pitch too regularly (or not at all).
+
+#![allow(dead_code)] // Some utilities reserved for future use
+
+use crate::utils::lpc::autocorrelation;
+
+/// Default minimum voice fundamental frequency (Hz)
+pub const MIN_VOICE_HZ: f64 = 50.0;
+
+/// Default maximum voice fundamental frequency (Hz)
+pub const MAX_VOICE_HZ: f64 = 500.0;
+
+/// Default voicing confidence threshold (fraction of r[0])
+pub const DEFAULT_VOICING_THRESHOLD: f64 = 0.3;
+
+/// A single voiced-frame pitch estimate
+#[derive(Debug, Clone, Copy)]
+pub struct PitchEstimate {
+    /// Estimated fundamental frequency in Hz
+    pub frequency: f64,
+    /// Sub-sample refined period in samples
+    pub period_samples: f64,
+    /// RMS amplitude of the frame, used for shimmer
+    pub amplitude: f64,
+}
+
+/// Estimate the fundamental frequency of a single windowed frame
+///
+/// Computes the normalized autocorrelation, restricts the lag search to
+/// `[sample_rate/max_hz, sample_rate/min_hz]`, picks the highest peak
+/// above `voicing_threshold * r[0]`, and refines it with parabolic
+/// interpolation over the three points around the peak for sub-sample
+/// accuracy.
+///
+/// # Returns
+/// `None` if the frame is unvoiced (no peak clears the voicing threshold)
+pub fn estimate_pitch(
+    frame: &[f64],
+    sample_rate: u32,
+    min_hz: f64,
+    max_hz: f64,
+    voicing_threshold: f64,
+) -> Option<PitchEstimate> {
+    if frame.len() < 4 || min_hz <= 0.0 || max_hz <= min_hz {
+        return None;
+    }
+
+    let sr = sample_rate as f64;
+    let min_lag = (sr / max_hz).floor().max(1.0) as usize;
+    let max_lag = (sr / min_hz).ceil() as usize;
+
+    if max_lag >= frame.len() || min_lag >= max_lag {
+        return None;
+    }
+
+    let r = autocorrelation(frame, max_lag);
+    if r[0].abs() < 1e-12 {
+        return None;
+    }
+
+    let threshold = voicing_threshold * r[0];
+
+    let mut best_lag = None;
+    let mut best_value = threshold;
+    for lag in min_lag..=max_lag {
+        if r[lag] > best_value {
+            best_value = r[lag];
+            best_lag = Some(lag);
+        }
+    }
+
+    let peak_lag = best_lag?;
+
+    // Parabolic interpolation over (peak_lag - 1, peak_lag, peak_lag + 1)
+    let refined_lag = if peak_lag > min_lag && peak_lag < max_lag {
+        let y0 = r[peak_lag - 1];
+        let y1 = r[peak_lag];
+        let y2 = r[peak_lag + 1];
+        let denom = y0 - 2.0 * y1 + y2;
+        if denom.abs() > 1e-12 {
+            peak_lag as f64 + 0.5 * (y0 - y2) / denom
+        } else {
+            peak_lag as f64
+        }
+    } else {
+        peak_lag as f64
+    };
+
+    if refined_lag <= 0.0 {
+        return None;
+    }
+
+    let amplitude = (frame.iter().map(|&x| x * x).sum::<f64>() / frame.len() as f64).sqrt();
+
+    Some(PitchEstimate {
+        frequency: sr / refined_lag,
+        period_samples: refined_lag,
+        amplitude,
+    })
+}
+
+/// Compute local jitter and shimmer from a voiced pitch-estimate track
+///
+/// * Jitter = mean(|T_i - T_{i-1}|) / mean(T), using period in samples
+/// * Shimmer = mean(|A_i - A_{i-1}|) / mean(A), using frame amplitude
+///
+/// # Returns
+/// `(jitter, shimmer)`, or `(0.0, 0.0)` if fewer than two estimates
+pub fn jitter_shimmer(track: &[PitchEstimate]) -> (f64, f64) {
+    if track.len() < 2 {
+        return (0.0, 0.0);
+    }
+
+    let mean_period = track.iter().map(|p| p.period_samples).sum::<f64>() / track.len() as f64;
+    let mean_amplitude = track.iter().map(|p| p.amplitude).sum::<f64>() / track.len() as f64;
+
+    if mean_period < 1e-9 || mean_amplitude < 1e-9 {
+        return (0.0, 0.0);
+    }
+
+    let mut period_diffs = 0.0;
+    let mut amplitude_diffs = 0.0;
+    for i in 1..track.len() {
+        period_diffs += (track[i].period_samples - track[i - 1].period_samples).abs();
+        amplitude_diffs += (track[i].amplitude - track[i - 1].amplitude).abs();
+    }
+
+    let n = (track.len() - 1) as f64;
+    let jitter = (period_diffs / n) / mean_period;
+    let shimmer = (amplitude_diffs / n) / mean_amplitude;
+
+    (jitter, shimmer)
+}
+
+/// Jitter below this fraction of mean F0 is suspiciously flat (synthetic)
+pub const JITTER_FLOOR: f64 = 0.005;
+
+/// Jitter above this fraction of mean F0 indicates erratic or failed tracking
+pub const JITTER_CEILING: f64 = 0.1;
+
+/// Score multiplier applied below `JITTER_FLOOR`
+const JITTER_LOW_FALLOFF: f64 = 0.5;
+
+/// Score multiplier applied above `JITTER_CEILING`
+const JITTER_HIGH_FALLOFF: f64 = 0.6;
+
+/// Score a frame-rate F0 track's cycle-to-cycle jitter for naturalness
+///
+/// Computes `jitter = mean(|F0_i - F0_{i-1}|) / mean(F0)` directly from a
+/// plain F0 track (unlike `jitter_shimmer`, which needs a full
+/// `PitchEstimate` track with period/amplitude). Jitter within
+/// `[JITTER_FLOOR, JITTER_CEILING]` (0.5%-10% of mean F0) scores 1.0;
+/// below the floor -- suspiciously flat, as many TTS/vocoder outputs
+/// produce -- the score is `JITTER_LOW_FALLOFF * jitter / JITTER_FLOOR`
+/// (at most 0.5, linear down to 0 at zero jitter); above the ceiling --
+/// erratic or failed tracking -- it is
+/// `JITTER_HIGH_FALLOFF * JITTER_CEILING / jitter` (at most 0.6, decaying
+/// toward 0 as jitter grows). Shared by `PitchSensor` and
+/// `ProsodySensor` so the two copies can't drift.
+///
+/// # Returns
+/// `0.5` (neutral) if the track is silent (near-zero mean F0)
+pub fn jitter_naturalness_score(f0_track: &[f64]) -> f64 {
+    let mean_f0 = f0_track.iter().sum::<f64>() / f0_track.len() as f64;
+    if mean_f0 < 1e-9 {
+        return 0.5;
+    }
+
+    let mut diffs = 0.0;
+    for i in 1..f0_track.len() {
+        diffs += (f0_track[i] - f0_track[i - 1]).abs();
+    }
+    let jitter = (diffs / (f0_track.len() - 1) as f64) / mean_f0;
+
+    if jitter < JITTER_FLOOR {
+        (jitter / JITTER_FLOOR).clamp(0.0, 1.0) * JITTER_LOW_FALLOFF
+    } else if jitter > JITTER_CEILING {
+        (JITTER_CEILING / jitter).clamp(0.0, 1.0) * JITTER_HIGH_FALLOFF
+    } else {
+        1.0
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn sine_frame(freq: f64, sample_rate: u32, n: usize) -> Vec<f64> {
+        (0..n)
+            .map(|i| (2.0 * std::f64::consts::PI * freq * i as f64 / sample_rate as f64).sin())
+            .collect()
+    }
+
+    #[test]
+    fn test_estimate_pitch_on_sine() {
+        let frame = sine_frame(150.0, 16000, 1024);
+        let estimate = estimate_pitch(&frame, 16000, MIN_VOICE_HZ, MAX_VOICE_HZ, DEFAULT_VOICING_THRESHOLD)
+            .expect("should detect pitch on a clean sine");
+        assert!((estimate.frequency - 150.0).abs() < 5.0);
+    }
+
+    #[test]
+    fn test_estimate_pitch_silent_frame() {
+        let frame = vec![0.0; 512];
+        assert!(estimate_pitch(&frame, 16000, MIN_VOICE_HZ, MAX_VOICE_HZ, DEFAULT_VOICING_THRESHOLD).is_none());
+    }
+
+    #[test]
+    fn test_jitter_shimmer_perfectly_stable() {
+        let track = vec![
+            PitchEstimate { frequency: 150.0, period_samples: 100.0, amplitude: 0.5 },
+            PitchEstimate { frequency: 150.0, period_samples: 100.0, amplitude: 0.5 },
+            PitchEstimate { frequency: 150.0, period_samples: 100.0, amplitude: 0.5 },
+        ];
+        let (jitter, shimmer) = jitter_shimmer(&track);
+        assert!((jitter - 0.0).abs() < f64::EPSILON);
+        assert!((shimmer - 0.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_jitter_shimmer_natural_variation() {
+        let track = vec![
+            PitchEstimate { frequency: 150.0, period_samples: 100.0, amplitude: 0.5 },
+            PitchEstimate { frequency: 151.0, period_samples: 101.0, amplitude: 0.51 },
+            PitchEstimate { frequency: 149.0, period_samples: 99.0, amplitude: 0.49 },
+        ];
+        let (jitter, shimmer) = jitter_shimmer(&track);
+        assert!(jitter > 0.0 && jitter < 0.05);
+        assert!(shimmer > 0.0 && shimmer < 0.05);
+    }
+
+    #[test]
+    fn test_jitter_naturalness_score_flat_track_scores_low() {
+        let track = vec![150.0; 10];
+        assert!(jitter_naturalness_score(&track) < 0.6);
+    }
+
+    #[test]
+    fn test_jitter_naturalness_score_natural_variation_scores_high() {
+        let track = vec![150.0, 151.5, 149.0, 150.8, 149.5, 150.2];
+        assert!((jitter_naturalness_score(&track) - 1.0).abs() < f64::EPSILON);
+    }
+
+    #[test]
+    fn test_jitter_naturalness_score_silent_track_is_neutral() {
+        assert!((jitter_naturalness_score(&[0.0; 10]) - 0.5).abs() < f64::EPSILON);
+    }
+}
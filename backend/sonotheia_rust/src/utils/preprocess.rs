@@ -0,0 +1,207 @@
+//! Canonical audio preprocessing front-end
+//!
+//! Sensors assume clean mono `&[f64]` at a fixed sample rate, but callers
+//! hand in audio at varying rates and channel counts. This module is the
+//! validated entry point that gets raw input into that canonical shape --
+//! downmix to mono, resample, remove DC offset, and normalize level --
+//! returning `SensorResultType` so a bad sample rate or empty buffer
+//! surfaces as a `SensorError` instead of a silent no-op.
+//!
+//! The individual operations already exist (`utils::io::resample`,
+//! `utils::audio::normalize_audio`, `utils::audio::apply_channel_op`);
+//! this module wraps them with the validation the raw-array `analyze`
+//! path needs and fills the one gap, `remove_dc`, that every sensor
+//! currently reimplements inline as a mean-subtraction.
+//!
+//! Not yet wired into any sensor's `analyze` path -- routing it in would
+//! add DC-removal and peak-normalization ahead of every sensor's scoring
+//! logic, which would shift the RMS/amplitude thresholds sensors are
+//! currently calibrated against. Kept available here for callers that
+//! want the canonical front-end without that tree-wide recalibration.
+
+#![allow(dead_code)]
+
+use crate::utils::audio::{
+    calculate_rms, normalize_audio, validate_audio_input, MAX_SAMPLE_RATE, MIN_SAMPLE_RATE,
+};
+use crate::utils::errors::{SensorError, SensorResultType};
+use crate::utils::io::resample as sinc_resample;
+
+/// Average parallel per-channel buffers down to mono
+///
+/// # Arguments
+/// * `channels` - One `&[f64]` per channel, all the same length
+pub fn to_mono(channels: &[&[f64]]) -> SensorResultType<Vec<f64>> {
+    match channels.len() {
+        0 => Err(SensorError::invalid_input("No channels to downmix")),
+        1 => Ok(channels[0].to_vec()),
+        n => {
+            let len = channels[0].len();
+            if channels.iter().any(|c| c.len() != len) {
+                return Err(SensorError::invalid_input("All channels must be the same length"));
+            }
+
+            Ok((0..len)
+                .map(|i| channels.iter().map(|c| c[i]).sum::<f64>() / n as f64)
+                .collect())
+        }
+    }
+}
+
+/// Resample `audio` from `from_hz` to `to_hz`, validating both rates
+///
+/// Both rates must fall within the `SensorError::InvalidSampleRate` range
+/// (8000-96000 Hz) sensors are tuned for. Delegates the actual
+/// interpolation to `utils::io::resample`'s band-limited sinc kernel,
+/// unlike `utils::audio::resample_to`'s plain linear interpolation.
+pub fn resample(audio: &[f64], from_hz: u32, to_hz: u32) -> SensorResultType<Vec<f64>> {
+    if audio.is_empty() {
+        return Err(SensorError::invalid_input("Cannot resample empty audio"));
+    }
+    if !(MIN_SAMPLE_RATE..=MAX_SAMPLE_RATE).contains(&from_hz) {
+        return Err(SensorError::invalid_sample_rate(from_hz));
+    }
+    if !(MIN_SAMPLE_RATE..=MAX_SAMPLE_RATE).contains(&to_hz) {
+        return Err(SensorError::invalid_sample_rate(to_hz));
+    }
+
+    Ok(sinc_resample(audio, from_hz, to_hz))
+}
+
+/// Subtract the mean to remove DC offset
+pub fn remove_dc(audio: &[f64]) -> SensorResultType<Vec<f64>> {
+    if audio.is_empty() {
+        return Err(SensorError::invalid_input("Cannot remove DC from empty audio"));
+    }
+
+    let mean = audio.iter().sum::<f64>() / audio.len() as f64;
+    Ok(audio.iter().map(|&x| x - mean).collect())
+}
+
+/// Normalization strategy for `normalize`
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum NormalizeMode {
+    /// Scale so the maximum absolute sample reaches 1.0
+    Peak,
+    /// Scale to a target RMS level ("gaussian" normalization)
+    Gaussian(f64),
+}
+
+/// Normalize `audio` to unit peak or a target RMS level
+///
+/// # Arguments
+/// * `audio` - Audio samples to normalize
+/// * `mode` - `Peak` for peak normalization, `Gaussian(target_rms)` for RMS normalization
+pub fn normalize(audio: &[f64], mode: NormalizeMode) -> SensorResultType<Vec<f64>> {
+    if audio.is_empty() {
+        return Err(SensorError::invalid_input("Cannot normalize empty audio"));
+    }
+
+    Ok(match mode {
+        NormalizeMode::Peak => normalize_audio(audio),
+        NormalizeMode::Gaussian(target_rms) => {
+            let rms = calculate_rms(audio);
+            if rms < 1e-9 {
+                audio.to_vec()
+            } else {
+                let gain = target_rms / rms;
+                audio.iter().map(|&x| x * gain).collect()
+            }
+        }
+    })
+}
+
+/// Downmix, resample, remove DC, and peak-normalize in one call
+///
+/// Brings arbitrary-rate, multichannel input down to the shape every
+/// sensor assumes -- mono, at `to_hz`, de-offset, unit peak. The sensors'
+/// raw-array `analyze` entry points do not call it (see the module docs).
+pub fn prepare(channels: &[&[f64]], from_hz: u32, to_hz: u32) -> SensorResultType<Vec<f64>> {
+    validate_audio_input(channels.first().copied().unwrap_or(&[]), from_hz)?;
+
+    let mono = to_mono(channels)?;
+    let resampled = resample(&mono, from_hz, to_hz)?;
+    let centered = remove_dc(&resampled)?;
+    normalize(&centered, NormalizeMode::Peak)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_mono_averages_channels() {
+        let left = vec![1.0, -1.0, 0.5];
+        let right = vec![-1.0, 1.0, 0.5];
+        let mono = to_mono(&[&left, &right]).unwrap();
+        assert_eq!(mono, vec![0.0, 0.0, 0.5]);
+    }
+
+    #[test]
+    fn test_to_mono_passthrough_single_channel() {
+        let mono = vec![0.1, 0.2, 0.3];
+        assert_eq!(to_mono(&[&mono]).unwrap(), mono);
+    }
+
+    #[test]
+    fn test_to_mono_rejects_mismatched_lengths() {
+        let left = vec![0.1, 0.2];
+        let right = vec![0.1];
+        assert!(to_mono(&[&left, &right]).is_err());
+    }
+
+    #[test]
+    fn test_to_mono_rejects_no_channels() {
+        assert!(to_mono(&[]).is_err());
+    }
+
+    #[test]
+    fn test_resample_rejects_out_of_range_rate() {
+        let audio = vec![0.1; 100];
+        assert!(resample(&audio, 4000, 16000).is_err());
+        assert!(resample(&audio, 16000, 200_000).is_err());
+    }
+
+    #[test]
+    fn test_resample_identity_when_rates_match() {
+        let audio = vec![0.1, 0.2, 0.3];
+        assert_eq!(resample(&audio, 16000, 16000).unwrap(), audio);
+    }
+
+    #[test]
+    fn test_remove_dc_zeroes_mean() {
+        let audio = vec![1.0, 2.0, 3.0];
+        let centered = remove_dc(&audio).unwrap();
+        let mean: f64 = centered.iter().sum::<f64>() / centered.len() as f64;
+        assert!(mean.abs() < 1e-12);
+    }
+
+    #[test]
+    fn test_remove_dc_rejects_empty() {
+        assert!(remove_dc(&[]).is_err());
+    }
+
+    #[test]
+    fn test_normalize_peak_reaches_unit_max() {
+        let audio = vec![0.2, -0.4, 0.1];
+        let normalized = normalize(&audio, NormalizeMode::Peak).unwrap();
+        let peak = normalized.iter().cloned().fold(0.0_f64, |a, b| a.max(b.abs()));
+        assert!((peak - 1.0).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_normalize_gaussian_reaches_target_rms() {
+        let audio = vec![0.1, -0.1, 0.1, -0.1];
+        let normalized = normalize(&audio, NormalizeMode::Gaussian(0.5)).unwrap();
+        let rms = calculate_rms(&normalized);
+        assert!((rms - 0.5).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_prepare_produces_canonical_mono_signal() {
+        let left = vec![0.5; 320];
+        let right = vec![0.5; 320];
+        let result = prepare(&[&left, &right], 8000, 16000).unwrap();
+        assert!((result.len() as i64 - 640).abs() <= 2);
+    }
+}